@@ -0,0 +1,248 @@
+pub const USAGE: &str = "\
+Edits JSONC files while keeping their comments and formatting.
+
+Usage: jsonc-morph <command> [options]
+
+Commands:
+  get <file> <pointer>         Prints the value at the JSON Pointer
+  set <file> <pointer> <json>  Sets the value at the JSON Pointer
+  delete <file> <pointer>      Removes the value at the JSON Pointer
+  merge <file> <patch-file>    Applies a JSON Merge Patch (RFC 7396)
+  fmt [--check] <file>...      Formats the files
+  check <file>...              Checks that the files are valid JSONC
+
+Use - as a file to read from stdin. Edits to stdin are written to stdout.
+
+Options:
+  -r, --raw    Prints strings from get without quotes
+  --check      Fails instead of formatting when a file isn't formatted
+  -h, --help   Prints this help";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+  Get {
+    file: String,
+    pointer: String,
+    raw: bool,
+  },
+  Set {
+    file: String,
+    pointer: String,
+    value: String,
+  },
+  Delete {
+    file: String,
+    pointer: String,
+  },
+  Merge {
+    file: String,
+    patch_file: String,
+  },
+  Fmt {
+    files: Vec<String>,
+    check: bool,
+  },
+  Check {
+    files: Vec<String>,
+  },
+  Help,
+}
+
+/// Parses the arguments, excluding the name of the binary.
+pub fn parse(args: &[String]) -> Result<Command, String> {
+  let mut raw = false;
+  let mut check = false;
+  let mut positional = Vec::new();
+  let mut args = args.iter();
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "-h" | "--help" => return Ok(Command::Help),
+      "-r" | "--raw" => raw = true,
+      "--check" => check = true,
+      // everything after is positional, such as a pointer starting with -
+      "--" => positional.extend(args.by_ref().cloned()),
+      "-" => positional.push(arg.clone()),
+      _ if is_negative_number(arg) => positional.push(arg.clone()),
+      _ if arg.starts_with('-') => {
+        return Err(format!("Unknown option '{}'", arg));
+      }
+      _ => positional.push(arg.clone()),
+    }
+  }
+
+  let Some((command, positional)) = positional.split_first() else {
+    return Ok(Command::Help);
+  };
+  let expect_no_flags =
+    |flags: &[(&str, bool)]| match flags.iter().find(|(_, is_set)| *is_set) {
+      Some((name, _)) => Err(format!(
+        "The {} option can't be used with the {} command",
+        name, command
+      )),
+      None => Ok(()),
+    };
+  let expect_args = |names: &[&str]| {
+    if positional.len() == names.len() {
+      Ok(())
+    } else {
+      Err(format!(
+        "Expected the {} command to have the arguments {}",
+        command,
+        names
+          .iter()
+          .map(|name| format!("<{}>", name))
+          .collect::<Vec<_>>()
+          .join(" ")
+      ))
+    }
+  };
+  let expect_files = || {
+    if positional.is_empty() {
+      Err(format!("Expected the {} command to have files", command))
+    } else {
+      Ok(positional.to_vec())
+    }
+  };
+
+  match command.as_str() {
+    "get" => {
+      expect_no_flags(&[("--check", check)])?;
+      expect_args(&["file", "pointer"])?;
+      Ok(Command::Get {
+        file: positional[0].clone(),
+        pointer: positional[1].clone(),
+        raw,
+      })
+    }
+    "set" => {
+      expect_no_flags(&[("--raw", raw), ("--check", check)])?;
+      expect_args(&["file", "pointer", "json"])?;
+      Ok(Command::Set {
+        file: positional[0].clone(),
+        pointer: positional[1].clone(),
+        value: positional[2].clone(),
+      })
+    }
+    "delete" => {
+      expect_no_flags(&[("--raw", raw), ("--check", check)])?;
+      expect_args(&["file", "pointer"])?;
+      Ok(Command::Delete {
+        file: positional[0].clone(),
+        pointer: positional[1].clone(),
+      })
+    }
+    "merge" => {
+      expect_no_flags(&[("--raw", raw), ("--check", check)])?;
+      expect_args(&["file", "patch-file"])?;
+      if positional[0] == "-" && positional[1] == "-" {
+        return Err(
+          "The file and patch file can't both be read from stdin".to_string(),
+        );
+      }
+      Ok(Command::Merge {
+        file: positional[0].clone(),
+        patch_file: positional[1].clone(),
+      })
+    }
+    "fmt" => {
+      expect_no_flags(&[("--raw", raw)])?;
+      Ok(Command::Fmt {
+        files: expect_files()?,
+        check,
+      })
+    }
+    "check" => {
+      expect_no_flags(&[("--raw", raw), ("--check", check)])?;
+      Ok(Command::Check {
+        files: expect_files()?,
+      })
+    }
+    "help" => Ok(Command::Help),
+    _ => Err(format!("Unknown command '{}'", command)),
+  }
+}
+
+/// Tests if the argument is a negative number, such as the value of `set`,
+/// rather than an option.
+fn is_negative_number(arg: &str) -> bool {
+  arg.strip_prefix('-').is_some_and(|number| {
+    number.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn parse_args(args: &[&str]) -> Result<Command, String> {
+    parse(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>())
+  }
+
+  #[test]
+  fn parses_commands() {
+    assert_eq!(
+      parse_args(&["get", "-r", "deno.json", "/tasks/build"]),
+      Ok(Command::Get {
+        file: "deno.json".to_string(),
+        pointer: "/tasks/build".to_string(),
+        raw: true,
+      })
+    );
+    assert_eq!(
+      parse_args(&["set", "-", "--", "/-a", "1"]),
+      Ok(Command::Set {
+        file: "-".to_string(),
+        pointer: "/-a".to_string(),
+        value: "1".to_string(),
+      })
+    );
+    assert_eq!(
+      parse_args(&["set", "f.json", "/a", "-1"]),
+      Ok(Command::Set {
+        file: "f.json".to_string(),
+        pointer: "/a".to_string(),
+        value: "-1".to_string(),
+      })
+    );
+    assert_eq!(
+      parse_args(&["fmt", "--check", "a.json", "b.json"]),
+      Ok(Command::Fmt {
+        files: vec!["a.json".to_string(), "b.json".to_string()],
+        check: true,
+      })
+    );
+    assert_eq!(parse_args(&[]), Ok(Command::Help));
+    assert_eq!(parse_args(&["check", "--help"]), Ok(Command::Help));
+  }
+
+  #[test]
+  fn rejects_invalid_arguments() {
+    assert_eq!(
+      parse_args(&["get", "a.json"]),
+      Err(
+        "Expected the get command to have the arguments <file> <pointer>"
+          .to_string()
+      )
+    );
+    assert_eq!(
+      parse_args(&["delete", "--raw", "a.json", "/a"]),
+      Err("The --raw option can't be used with the delete command".to_string())
+    );
+    assert_eq!(
+      parse_args(&["merge", "-", "-"]),
+      Err("The file and patch file can't both be read from stdin".to_string())
+    );
+    assert_eq!(
+      parse_args(&["check"]),
+      Err("Expected the check command to have files".to_string())
+    );
+    assert_eq!(
+      parse_args(&["get", "--other"]),
+      Err("Unknown option '--other'".to_string())
+    );
+    assert_eq!(
+      parse_args(&["other"]),
+      Err("Unknown command 'other'".to_string())
+    );
+  }
+}
@@ -0,0 +1,210 @@
+use rs_lib::format;
+use rs_lib::jsonc_parser;
+use rs_lib::jsonc_parser::ParseOptions;
+use rs_lib::jsonc_parser::cst::CstRootNode;
+use rs_lib::merge;
+use rs_lib::pointer;
+use rs_lib::position;
+use rs_lib::values;
+use serde_json::Value;
+
+/// Gets the value at the pointer as pretty printed JSON, or as the text of
+/// the string when `raw` is set.
+pub fn get(text: &str, pointer: &str, raw: bool) -> Result<String, String> {
+  let root = parse(text)?;
+  let node = pointer::get(&root, pointer)
+    .map_err(|err| err.to_string())?
+    .ok_or_else(|| format!("No value exists at '{}'", pointer))?;
+  let value = values::to_serde_value(&node)
+    .ok_or_else(|| format!("Expected a value at '{}'", pointer))?;
+  Ok(match value {
+    Value::String(text) if raw => text,
+    value => serde_json::to_string_pretty(&value).unwrap(),
+  })
+}
+
+/// Sets the value at the pointer to the JSONC text of a value, adding any
+/// missing objects.
+pub fn set(text: &str, pointer: &str, value: &str) -> Result<String, String> {
+  let root = parse(text)?;
+  let value = parse_value(value, root.parse_options())?;
+  pointer::set(&root, pointer, value).map_err(|err| err.to_string())?;
+  Ok(root.to_string())
+}
+
+/// Removes the value at the pointer. Does nothing when there's no value.
+pub fn delete(text: &str, pointer: &str) -> Result<String, String> {
+  let root = parse(text)?;
+  pointer::remove(&root, pointer).map_err(|err| err.to_string())?;
+  Ok(root.to_string())
+}
+
+/// Parses the JSONC text of a JSON Merge Patch.
+pub fn parse_patch(text: &str) -> Result<Value, String> {
+  jsonc_parser::parse_to_serde_value::<Option<Value>>(
+    text,
+    &ParseOptions::default(),
+  )
+  .map_err(|err| parse_error_message(&err, text))?
+  .ok_or_else(|| "Expected the patch to contain a value".to_string())
+}
+
+/// Applies a JSON Merge Patch.
+pub fn merge(text: &str, patch: &Value) -> Result<String, String> {
+  let root = parse(text)?;
+  merge::merge_into_root(&root, patch);
+  Ok(root.to_string())
+}
+
+/// Formats the text with the same defaults as dprint-plugin-json.
+pub fn fmt(text: &str) -> Result<String, String> {
+  let root = parse(text)?;
+  Ok(format::format(&root, &format::FormatOptions::dprint(&root)))
+}
+
+/// Checks that the text is valid JSONC.
+pub fn check(text: &str) -> Result<(), String> {
+  parse(text).map(|_| ())
+}
+
+fn parse(text: &str) -> Result<CstRootNode, String> {
+  CstRootNode::parse(text, &ParseOptions::default())
+    .map_err(|err| parse_error_message(&err, text))
+}
+
+fn parse_value(
+  text: &str,
+  options: &ParseOptions,
+) -> Result<values::InsertValue, String> {
+  values::InsertValue::parse(text, options).map_err(|err| match err {
+    values::InsertValueError::Parse(err) => {
+      format!("Invalid value: {}", parse_error_message(&err, text))
+    }
+    values::InsertValueError::NoValue => {
+      "Expected the value to set".to_string()
+    }
+  })
+}
+
+/// Describes a parse error like `ParseError.message` in the JavaScript API,
+/// followed by the code frame.
+fn parse_error_message(
+  err: &jsonc_parser::errors::ParseError,
+  text: &str,
+) -> String {
+  let range = position::TextRange {
+    start: err.range().start,
+    end: err.range().end,
+  };
+  format!(
+    "{}\n\n{}",
+    position::parse_error_message(err, text),
+    position::code_frame(text, range)
+  )
+}
+
+#[cfg(test)]
+mod test {
+  use serde_json::json;
+
+  use super::*;
+
+  const TEXT: &str = "{
+  // the tasks
+  \"tasks\": {
+    \"build\": \"cargo build\" // comment
+  }
+}
+";
+
+  #[test]
+  fn gets_values() {
+    assert_eq!(get(TEXT, "/tasks/build", false).unwrap(), "\"cargo build\"");
+    assert_eq!(get(TEXT, "/tasks/build", true).unwrap(), "cargo build");
+    assert_eq!(
+      get(TEXT, "/tasks", true).unwrap(),
+      "{\n  \"build\": \"cargo build\"\n}"
+    );
+    assert_eq!(
+      get(TEXT, "/other", false),
+      Err("No value exists at '/other'".to_string())
+    );
+  }
+
+  #[test]
+  fn sets_values_keeping_comments() {
+    assert_eq!(
+      set(TEXT, "/tasks/build", "'cargo build --release'").unwrap(),
+      "{
+  // the tasks
+  \"tasks\": {
+    \"build\": 'cargo build --release' // comment
+  }
+}
+"
+    );
+    assert_eq!(
+      set(TEXT, "/lint/rules", "[0x1]").unwrap(),
+      "{
+  // the tasks
+  \"tasks\": {
+    \"build\": \"cargo build\" // comment
+  },
+  \"lint\": {
+    \"rules\": [0x1]
+  }
+}
+"
+    );
+    assert_eq!(
+      set(TEXT, "/tasks/test", "// run them all\n'cargo test' // slow")
+        .unwrap(),
+      "{
+  // the tasks
+  \"tasks\": {
+    \"build\": \"cargo build\", // comment
+    // run them all
+    \"test\": 'cargo test' // slow
+  }
+}
+"
+    );
+    assert!(
+      set(TEXT, "/a", "{")
+        .unwrap_err()
+        .starts_with("Invalid value: ")
+    );
+  }
+
+  #[test]
+  fn deletes_values() {
+    assert_eq!(
+      delete(TEXT, "/tasks/build").unwrap(),
+      "{\n  // the tasks\n  \"tasks\": {}\n}\n"
+    );
+    assert_eq!(delete(TEXT, "/other").unwrap(), TEXT);
+  }
+
+  #[test]
+  fn merges_patches() {
+    let patch =
+      parse_patch("{ /* comment */ \"tasks\": { \"build\": null } }").unwrap();
+    assert_eq!(patch, json!({ "tasks": { "build": null } }));
+    assert_eq!(
+      merge(TEXT, &patch).unwrap(),
+      "{\n  // the tasks\n  \"tasks\": {}\n}\n"
+    );
+  }
+
+  #[test]
+  fn formats_and_checks() {
+    assert_eq!(fmt("{'a':[1,2]}").unwrap(), "{ 'a': [1, 2] }\n");
+    assert_eq!(fmt(TEXT).unwrap(), TEXT);
+    assert!(check(TEXT).is_ok());
+    assert!(
+      check("{\n  \"a\": 1,\n  \"b\": [\n}")
+        .unwrap_err()
+        .starts_with("Unexpected close brace on line 4 column 1\n\n")
+    );
+  }
+}
@@ -0,0 +1,123 @@
+//! Command-line tool for editing JSONC files while keeping their comments
+//! and formatting.
+
+use std::io::Read;
+use std::process::ExitCode;
+
+use args::Command;
+
+mod args;
+mod commands;
+
+fn main() -> ExitCode {
+  let args = std::env::args().skip(1).collect::<Vec<_>>();
+  match run(&args) {
+    Ok(code) => code,
+    Err(message) => {
+      eprintln!("error: {}", message);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, String> {
+  match args::parse(args)? {
+    Command::Help => println!("{}", args::USAGE),
+    Command::Get { file, pointer, raw } => {
+      let text = read(&file)?;
+      let value = commands::get(&text, &pointer, raw)
+        .map_err(|err| in_file(&file, err))?;
+      println!("{}", value);
+    }
+    Command::Set {
+      file,
+      pointer,
+      value,
+    } => edit(&file, |text| commands::set(text, &pointer, &value))?,
+    Command::Delete { file, pointer } => {
+      edit(&file, |text| commands::delete(text, &pointer))?
+    }
+    Command::Merge { file, patch_file } => {
+      let patch = commands::parse_patch(&read(&patch_file)?)
+        .map_err(|err| in_file(&patch_file, err))?;
+      edit(&file, |text| commands::merge(text, &patch))?
+    }
+    Command::Fmt { files, check } => {
+      let mut unformatted = 0;
+      for file in &files {
+        let text = read(file)?;
+        let formatted =
+          commands::fmt(&text).map_err(|err| in_file(file, err))?;
+        if !check {
+          write(file, &text, &formatted)?;
+        } else if formatted != text {
+          eprintln!("Not formatted: {}", display_name(file));
+          unformatted += 1;
+        }
+      }
+      if unformatted > 0 {
+        eprintln!(
+          "error: Found {} not formatted file{}",
+          unformatted,
+          if unformatted == 1 { "" } else { "s" }
+        );
+        return Ok(ExitCode::FAILURE);
+      }
+    }
+    Command::Check { files } => {
+      let mut is_valid = true;
+      for file in &files {
+        if let Err(err) = commands::check(&read(file)?) {
+          eprintln!("error: {}", in_file(file, err));
+          is_valid = false;
+        }
+      }
+      if !is_valid {
+        return Ok(ExitCode::FAILURE);
+      }
+    }
+  }
+  Ok(ExitCode::SUCCESS)
+}
+
+/// Edits the file in place, or writes the edited stdin to stdout.
+fn edit(
+  file: &str,
+  edit: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), String> {
+  let text = read(file)?;
+  let edited = edit(&text).map_err(|err| in_file(file, err))?;
+  write(file, &text, &edited)
+}
+
+fn read(file: &str) -> Result<String, String> {
+  if file == "-" {
+    let mut text = String::new();
+    std::io::stdin()
+      .read_to_string(&mut text)
+      .map_err(|err| format!("Failed to read stdin: {}", err))?;
+    Ok(text)
+  } else {
+    std::fs::read_to_string(file)
+      .map_err(|err| format!("Failed to read '{}': {}", file, err))
+  }
+}
+
+/// Writes the text to the file when it changed, or to stdout for stdin.
+fn write(file: &str, old_text: &str, text: &str) -> Result<(), String> {
+  if file == "-" {
+    print!("{}", text);
+  } else if old_text != text {
+    std::fs::write(file, text)
+      .map_err(|err| format!("Failed to write '{}': {}", file, err))?;
+  }
+  Ok(())
+}
+
+fn in_file(file: &str, message: String) -> String {
+  format!("{}: {}", display_name(file), message)
+}
+
+fn display_name(file: &str) -> &str {
+  if file == "-" { "<stdin>" } else { file }
+}
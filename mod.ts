@@ -1,8 +1,15 @@
 export {
   BooleanLit,
+  type CommentInfo,
+  type CommentOptions,
+  type DiffOptions,
+  format,
+  type FormatOptions,
   JsonArray,
   JsonObject,
+  type JsonPatchOperation,
   type JsonValue,
+  type LineColumn,
   Node,
   NullKeyword,
   NumberLit,
@@ -12,18 +19,97 @@ export {
   type ParseOptions,
   parseToValue,
   RootNode,
+  type SetNumberOptions,
+  type SortOptions,
   StringLit,
+  type TextRange,
+  type ToValueOptions,
+  validate,
+  type ValidationError,
   WordLit,
 } from "./lib/rs_lib.js";
 
 import {
+  diff as diffInputs,
+  DiffInput,
+  type DiffOptions,
+  type JsonPatchOperation,
   type JsonValue,
+  type LineColumn,
   parse,
   type ParseOptions,
   parseToValue,
-  type RootNode,
+  RootNode,
+  setErrorClasses,
+  type TextRange,
+  type ToValueOptions,
 } from "./lib/rs_lib.js";
 
+/**
+ * Error thrown when parsing JSONC text fails.
+ * Contains the location of the error so it can be reported to users.
+ */
+export class ParseError extends Error {
+  /** A stable code for the kind of error (ex. "EXPECTED_COMMA"). */
+  declare readonly code: string;
+  /** The start and end byte offsets of the error in the text. */
+  declare readonly range: TextRange;
+  /**
+   * The start and end offsets of the error in UTF-16 code units,
+   * which can be used directly with JavaScript string indexes.
+   */
+  declare readonly utf16Range: TextRange;
+  /** The 1-based line and column where the error starts. */
+  declare readonly start: LineColumn;
+  /** The 1-based line and column where the error ends. */
+  declare readonly end: LineColumn;
+  /** The lines surrounding the error with the error's range underlined. */
+  declare readonly codeFrame: string;
+
+  /** Returns the message followed by the code frame. */
+  override toString(): string {
+    return `${this.message}\n\n${this.codeFrame}`;
+  }
+}
+ParseError.prototype.name = "ParseError";
+
+/**
+ * Error thrown when a node isn't what an operation expects, such as by the
+ * `*OrThrow` methods.
+ * Contains the code of the failure and the node it occurred at so it can be
+ * handled without matching the message.
+ */
+export class JsoncMorphError extends Error {
+  /** A stable code for the kind of error (ex. "PROPERTY_NOT_FOUND"). */
+  declare readonly code: string;
+  /** The property name that was looked up, if any. */
+  declare readonly key?: string;
+  /** The kind of node that was expected (ex. "object" or "string"). */
+  declare readonly expected?: string;
+  /** The kind of node that was found, or undefined when there was none. */
+  declare readonly actual?: string;
+  /**
+   * The JSON Pointer of the node the error occurred at, or undefined when
+   * the node isn't attached to a document.
+   */
+  declare readonly pointer?: string;
+  /** The start and end byte offsets of the node the error occurred at. */
+  declare readonly range?: TextRange;
+  /**
+   * The start and end offsets of the node the error occurred at in UTF-16
+   * code units, which can be used directly with JavaScript string indexes.
+   */
+  declare readonly utf16Range?: TextRange;
+
+  /** Returns the code followed by the message. */
+  override toString(): string {
+    return `${this.code}: ${this.message}`;
+  }
+}
+JsoncMorphError.prototype.name = "JsoncMorphError";
+
+setErrorClasses(ParseError, JsoncMorphError);
+
 /**
  * Options for strict JSON parsing (all JSONC extensions disabled by default).
  * @deprecated Use `ParseOptions` instead - this is an alias for backwards compatibility.
@@ -60,13 +146,45 @@ export function parseStrict(
  * By default, all JSONC extensions are disabled (no comments, no trailing commas, etc.).
  * You can selectively enable extensions by setting options to `true`.
  * @param text - The JSON text to parse
- * @param options - Optional parsing options (all default to `false`)
+ * @param options - Optional parsing options (all default to `false`) and conversion options
  * @returns The plain JavaScript value (object, array, string, number, boolean, or null)
  * @throws If the text cannot be parsed or converted
  */
 export function parseToValueStrict(
   text: string,
-  options?: ParseOptions,
+  options?: ParseOptions & ToValueOptions,
 ): JsonValue {
   return parseToValue(text, { ...STRICT_DEFAULTS, ...options });
 }
+
+/**
+ * Computes the JSON Patch (RFC 6902) operations that transform `a` into `b`.
+ * Documents are compared by their values, so comments and formatting are ignored.
+ * @param a - The original document or value
+ * @param b - The new document or value
+ * @param options - Optional diff options
+ * @returns The patch operations, which can be passed to `RootNode.applyPatch`
+ */
+export function diff(
+  a: RootNode | JsonValue,
+  b: RootNode | JsonValue,
+  options?: DiffOptions,
+): JsonPatchOperation[] {
+  // free the first input even when converting the second one throws
+  let inputA: DiffInput | undefined;
+  let inputB: DiffInput | undefined;
+  try {
+    inputA = toDiffInput(a);
+    inputB = toDiffInput(b);
+    return diffInputs(inputA, inputB, options);
+  } finally {
+    inputA?.free();
+    inputB?.free();
+  }
+}
+
+function toDiffInput(value: RootNode | JsonValue): DiffInput {
+  return value instanceof RootNode
+    ? DiffInput.fromRoot(value)
+    : DiffInput.fromValue(value);
+}
@@ -1,5 +1,21 @@
-import { assertEquals, assertExists } from "@std/assert";
-import { parse, parseStrict, parseToValue, parseToValueStrict } from "./mod.ts";
+import {
+  assertEquals,
+  assertExists,
+  assertInstanceOf,
+  assertThrows,
+} from "@std/assert";
+import {
+  diff,
+  format,
+  JsoncMorphError,
+  type Node,
+  parse,
+  ParseError,
+  parseStrict,
+  parseToValue,
+  parseToValueStrict,
+  validate,
+} from "./mod.ts";
 
 Deno.test("RootNode - parse simple object", () => {
   const text = '{"name": "test", "value": 42}';
@@ -395,6 +411,1125 @@ Deno.test("Parse error handling", () => {
   }
 });
 
+Deno.test("ParseError - contains location information", () => {
+  const text = `{
+  "a": 1,
+  "b": ]
+}`;
+  const error = assertThrows(() => parse(text));
+  assertInstanceOf(error, ParseError);
+  assertInstanceOf(error, Error);
+  assertEquals(error.name, "ParseError");
+  assertExists(error.stack);
+  assertEquals(error.code, "UNEXPECTED_CLOSE_BRACKET");
+  assertEquals(error.message, "Unexpected close bracket on line 3 column 8");
+  assertEquals(error.range, { start: 19, end: 20 });
+  assertEquals(error.utf16Range, { start: 19, end: 20 });
+  assertEquals(error.start, { line: 3, column: 8 });
+  assertEquals(error.end, { line: 3, column: 9 });
+  assertEquals(
+    error.codeFrame,
+    `  2 |   "a": 1,
+> 3 |   "b": ]
+    |        ^
+  4 | }`,
+  );
+});
+
+Deno.test("ParseError - utf16 range differs from byte range", () => {
+  const error = assertThrows(() => parseToValue(`["é", 'x]`));
+  assertInstanceOf(error, ParseError);
+  assertEquals(error.code, "UNTERMINATED_STRING_LITERAL");
+  assertEquals(error.range, { start: 7, end: 10 });
+  assertEquals(error.utf16Range, { start: 6, end: 9 });
+  assertEquals(error.start, { line: 1, column: 7 });
+});
+
+Deno.test("JsoncMorphError - thrown by OrThrow methods", () => {
+  const root = parse(`{
+  "a": { "b": 1 },
+  "c": [true]
+}`);
+  const obj = root.asObjectOrThrow();
+
+  const notFound = assertThrows(() => obj.getOrThrow("x"));
+  assertInstanceOf(notFound, JsoncMorphError);
+  assertInstanceOf(notFound, Error);
+  assertEquals(notFound.name, "JsoncMorphError");
+  assertExists(notFound.stack);
+  assertEquals(notFound.code, "PROPERTY_NOT_FOUND");
+  assertEquals(notFound.message, "Expected property 'x', but it was not found");
+  assertEquals(notFound.key, "x");
+  assertEquals(notFound.expected, undefined);
+  assertEquals(notFound.pointer, "");
+
+  const notArray = assertThrows(() => obj.getIfArrayOrThrow("a"));
+  assertInstanceOf(notArray, JsoncMorphError);
+  assertEquals(notArray.code, "EXPECTED_ARRAY");
+  assertEquals(notArray.key, "a");
+  assertEquals(notArray.expected, "array");
+  assertEquals(notArray.actual, "object");
+  assertEquals(notArray.pointer, "/a");
+  assertEquals(notArray.range, { start: 9, end: 19 });
+
+  const element = obj.getOrThrow("c").valueIfArrayOrThrow().elements()[0];
+  const notString = assertThrows(() => element.asStringOrThrow());
+  assertInstanceOf(notString, JsoncMorphError);
+  assertEquals(notString.code, "WRONG_VALUE_KIND");
+  assertEquals(notString.expected, "string");
+  assertEquals(notString.actual, "boolean");
+  assertEquals(notString.pointer, "/c/0");
+  assertEquals(notString.range, { start: 29, end: 33 });
+  assertEquals(
+    notString.toString(),
+    "WRONG_VALUE_KIND: Expected a string node, but found a different type",
+  );
+
+  const empty = assertThrows(() => parse("").valueOrThrow());
+  assertInstanceOf(empty, JsoncMorphError);
+  assertEquals(empty.code, "EXPECTED_VALUE");
+
+  const notObject = assertThrows(() => parse("1").asObjectOrThrow());
+  assertInstanceOf(notObject, JsoncMorphError);
+  assertEquals(notObject.code, "EXPECTED_OBJECT");
+  assertEquals(notObject.actual, "number");
+  assertEquals(notObject.pointer, "");
+});
+
+Deno.test("Detached nodes - isAttached and throwing on changes", () => {
+  const root = parse(`{
+  "a": { "b": 1 },
+  "c": [true, "d"]
+}`);
+  const obj = root.asObjectOrThrow();
+  assertEquals(root.isAttached(), true);
+  assertEquals(obj.isAttached(), true);
+
+  const a = obj.getOrThrow("a");
+  const b = a.valueIfObjectOrThrow().getOrThrow("b");
+  obj.getOrThrow("a").remove();
+  assertEquals(a.isAttached(), false);
+  assertEquals(b.isAttached(), false);
+  const detached = assertThrows(() => b.setValue(2));
+  assertInstanceOf(detached, JsoncMorphError);
+  assertEquals(detached.code, "DETACHED_NODE");
+  assertEquals(detached.pointer, undefined);
+  assertThrows(() => a.remove());
+
+  const array = obj.getIfArrayOrThrow("c");
+  const str = array.elements()[1].asStringOrThrow();
+  array.elements()[1].asStringOrThrow().replaceWith(false);
+  assertEquals(str.isAttached(), false);
+  assertThrows(() => str.setRawValue(`"e"`));
+  assertEquals(array.isAttached(), true);
+  array.append(3);
+
+  const copy = obj.getOrThrow("c").valueOrThrow().clone();
+  assertEquals(copy.isAttached(), false);
+  assertThrows(() => copy.asArrayOrThrow().append(4));
+  obj.append("copy", copy);
+
+  assertEquals(
+    root.toString(),
+    `{
+  "c": [true, false, 3],
+  "copy": [true, false, 3]
+}`,
+  );
+});
+
+Deno.test("validate - errors located in the text", () => {
+  const root = parse(`{
+  // the name
+  "name": 1,
+  "tasks": { "build": "npm run" },
+  "extra": true
+}`);
+  const schema = {
+    type: "object",
+    $defs: { task: { type: "string", pattern: "^deno " } },
+    properties: {
+      name: { type: "string" },
+      version: { type: "string" },
+      tasks: { additionalProperties: { $ref: "#/$defs/task" } },
+    },
+    required: ["name", "version"],
+    additionalProperties: false,
+  };
+  const errors = validate(root, schema);
+  assertEquals(errors[0], {
+    keyword: "type",
+    message: "Expected a string, but found a number",
+    pointer: "/name",
+    schemaPointer: "/properties/name/type",
+    range: { start: 26, end: 27 },
+    utf16Range: { start: 26, end: 27 },
+    start: { line: 3, column: 11 },
+    end: { line: 3, column: 12 },
+  });
+  assertEquals(
+    errors.slice(1).map((e) => [e.keyword, e.pointer, e.schemaPointer, e.key]),
+    [
+      ["pattern", "/tasks/build", "/$defs/task/pattern", undefined],
+      ["additionalProperties", "/extra", "/additionalProperties", "extra"],
+      ["required", "", "/required", "version"],
+    ],
+  );
+  assertEquals(errors[2].range, { start: 66, end: 79 });
+
+  const obj = root.asObjectOrThrow();
+  obj.getOrThrow("name").setValue("jsonc-morph");
+  obj.getOrThrow("extra").remove();
+  obj.getIfObjectOrThrow("tasks").getOrThrow("build").setValue("deno task");
+  obj.append("version", "1.0.0");
+  assertEquals(validate(root, schema), []);
+
+  assertThrows(
+    () => validate(root, { $ref: "other.json" }),
+    Error,
+    "Invalid schema at '/$ref'",
+  );
+  assertThrows(
+    () => validate(root, { properties: { name: { pattern: "(" } } }),
+    Error,
+    "Invalid schema at '/properties/name/pattern': invalid pattern '('",
+  );
+});
+
+Deno.test("Node ranges - byte, utf16, and line/column", () => {
+  const text = `{
+  "é": [1, true],
+  "b": null
+}`;
+  const root = parse(text);
+  const prop = root.asObjectOrThrow().getOrThrow("é");
+  assertEquals(prop.range(), { start: 4, end: 19 });
+  assertEquals(prop.utf16Range(), { start: 4, end: 18 });
+  assertEquals(prop.startLineColumn(), { line: 2, column: 3 });
+  assertEquals(prop.endLineColumn(), { line: 2, column: 17 });
+
+  const trueNode = prop.valueOrThrow().asArrayOrThrow().elements()[1];
+  const range = trueNode.utf16Range();
+  assertEquals(text.slice(range.start, range.end), "true");
+  assertEquals(prop.nameOrThrow().range(), { start: 4, end: 8 });
+  assertEquals(root.utf16Range(), { start: 0, end: text.length });
+});
+
+Deno.test("Node ranges - recomputed after edits", () => {
+  const root = parse(`{ "a": 1, "b": 2 }`);
+  const obj = root.asObjectOrThrow();
+  const b = obj.getOrThrow("b");
+  assertEquals(b.range(), { start: 10, end: 16 });
+  obj.getOrThrow("a").remove();
+  assertEquals(b.range(), { start: 2, end: 8 });
+  assertEquals(b.startLineColumn(), { line: 1, column: 3 });
+});
+
+Deno.test("Node lookup - nodeAtOffset and nodeAtLineColumn", () => {
+  const text = `{
+  "a": [1, true],
+  "b": null
+}`;
+  const root = parse(text);
+  assertEquals(root.nodeAtOffset(14)?.asBooleanOrThrow().value(), true);
+  assertEquals(root.nodeAtOffset(4)?.isString(), true);
+  assertEquals(root.nodeAtOffset(4)?.parentOrThrow().range(), {
+    start: 4,
+    end: 18,
+  });
+  assertEquals(root.nodeAtOffset(text.length), undefined);
+
+  const node = root.nodeAtLineColumn(3, 8);
+  assertEquals(node?.isNull(), true);
+  assertEquals(root.nodeAtLineColumn(3, 100), undefined);
+  assertEquals(root.nodeAtLineColumn(10, 1), undefined);
+});
+
+Deno.test("Node lookup - nodesInRange", () => {
+  const text = `{ "a": [1, true], "b": null }`;
+  const root = parse(text);
+  const textOf = (node: Node) => {
+    const range = node.range();
+    return text.slice(range.start, range.end);
+  };
+  const nodes = root.nodesInRange(2, 16);
+  assertEquals(nodes.map(textOf), [`"a": [1, true]`]);
+
+  const partial = root.nodesInRange(12, 24);
+  assertEquals(partial.map(textOf), [
+    "true",
+    "]",
+    ",",
+    " ",
+    '"b"',
+    ":",
+    " ",
+  ]);
+  assertEquals(root.nodesInRange(5, 5), []);
+});
+
+Deno.test("JSON Pointer - getPointer", () => {
+  const root = parse(`{
+  // comment
+  "compilerOptions": {
+    "paths": { "/foo": ["a", "b"], "~x": 1 }
+  }
+}`);
+  assertEquals(
+    root.getPointer("/compilerOptions/paths/~1foo/1")?.toValue(),
+    "b",
+  );
+  assertEquals(root.getPointer("/compilerOptions/paths/~0x")?.toValue(), 1);
+  assertEquals(root.getPointer("/compilerOptions/paths/~1foo/01"), undefined);
+  assertEquals(root.getPointer("/missing"), undefined);
+  assertEquals(root.getPointer("")?.isContainer(), true);
+  assertThrows(() => root.getPointer("compilerOptions"));
+  assertThrows(() => root.getPointer("/a~2"));
+});
+
+Deno.test("JSON Pointer - setPointer and removePointer", () => {
+  const root = parse(`{
+  // comment
+  "compilerOptions": {},
+  "arr": [1, 2]
+}`);
+  root.setPointer("/compilerOptions/strict", true);
+  root.setPointer("/a/b", 1);
+  root.setPointer("/arr/-", 3);
+  root.setPointer("/arr/0", "z");
+  assertThrows(() => root.setPointer("/arr/9", 1));
+  assertThrows(() => root.setPointer("/arr/0/x", 1));
+  assertEquals(root.removePointer("/arr/1"), true);
+  assertEquals(root.removePointer("/missing"), false);
+  assertEquals(
+    root.toString(),
+    `{
+  // comment
+  "compilerOptions": {
+    "strict": true
+  },
+  "arr": ["z", 3],
+  "a": {
+    "b": 1
+  }
+}`,
+  );
+});
+
+Deno.test("JSON Pointer - pointerOf", () => {
+  const root = parse(`{ "a/b": [1, { "c": true }] }`);
+  const node = root.getPointer("/a~1b/1/c")!;
+  assertEquals(root.pointerOf(node), "/a~1b/1/c");
+  assertEquals(root.pointerOf(root.nodeAtOffset(10)!), "/a~1b/0");
+  // tokens resolve to the containing value
+  assertEquals(root.pointerOf(root.nodeAtOffset(11)!), "/a~1b");
+  assertEquals(root.pointerOf(root.valueOrThrow()), "");
+  // nodes of other documents have no pointer
+  assertEquals(parse(`{ "d": 1 }`).pointerOf(node), undefined);
+});
+
+Deno.test("JSON Pointer - appending to multi-line arrays", () => {
+  const root = parse(`{
+  "list": [
+    1
+  ]
+}`);
+  root.setPointer("/list/-", 2);
+  root.setPointer("/list/-", 3);
+  const node = root.getPointer("/list/2")!;
+  assertEquals(node.toValue(), 3);
+  assertEquals(root.pointerOf(node), "/list/2");
+  root.applyPatch([{ op: "add", path: "/list/1", value: [4] }]);
+  assertEquals(
+    root.toString(),
+    `{
+  "list": [
+    1,
+    [4],
+    2,
+    3
+  ]
+}`,
+  );
+});
+
+Deno.test("applyPatch - applies operations and keeps comments", () => {
+  const root = parse(`{
+  // keep me
+  "a": 1,
+  "list": [1, 0xFF, 3], // trailing
+  "obj": { "x": true }
+}`);
+  root.applyPatch([
+    { op: "test", path: "/a", value: 1 },
+    { op: "replace", path: "/obj/x", value: false },
+    { op: "move", from: "/list/1", path: "/moved" },
+    { op: "copy", from: "/a", path: "/b" },
+    { op: "remove", path: "/list/1" },
+  ]);
+  assertEquals(
+    root.toString(),
+    `{
+  // keep me
+  "a": 1,
+  "list": [1], // trailing
+  "obj": { "x": false },
+  "moved": 0xFF,
+  "b": 1
+}`,
+  );
+});
+
+Deno.test("applyPatch - is atomic", () => {
+  const text = `{
+  // keep me
+  "a": 1
+}`;
+  const root = parse(text);
+  assertThrows(
+    () =>
+      root.applyPatch([
+        { op: "remove", path: "/a" },
+        { op: "test", path: "/b", value: 2 },
+      ]),
+    Error,
+    "Patch operation 1 failed",
+  );
+  assertThrows(() =>
+    root.applyPatch([
+      { op: "add", path: "/b", value: 2 },
+      { op: "add", path: "/missing/c", value: 3 },
+    ])
+  );
+  assertEquals(root.toString(), text);
+});
+
+Deno.test("mergePatch - RootNode", () => {
+  const root = parse(`{
+  // comment
+  "a": 0x1, // keep
+  "b": { "x": 1, "y": 2 },
+  "c": [1],
+  "d": "s"
+}`);
+  root.mergePatch({
+    a: 1,
+    b: { x: null },
+    c: { n: true },
+    d: null,
+    e: { f: null, g: 1 },
+  });
+  assertEquals(
+    root.toString(),
+    `{
+  // comment
+  "a": 0x1, // keep
+  "b": { "y": 2 },
+  "c": {
+    "n": true
+  },
+  "e": {
+    "g": 1
+  }
+}`,
+  );
+
+  root.mergePatch([1]);
+  assertEquals(root.toString(), "[1]");
+});
+
+Deno.test("mergePatch - JsonObject", () => {
+  const root = parse(`{ "compilerOptions": { "strict": false } }`);
+  const obj = root.asObjectOrThrow().getIfObjectOrThrow("compilerOptions");
+  obj.mergePatch({ strict: true, lib: ["esnext"] });
+  assertEquals(root.toValue(), {
+    compilerOptions: { strict: true, lib: ["esnext"] },
+  });
+  assertThrows(() => obj.mergePatch(1));
+});
+
+Deno.test("diff - values", () => {
+  assertEquals(diff({ a: 1 }, { a: 1 }), []);
+  assertEquals(diff({ a: 1, b: 2 }, { a: 3, c: 4 }), [
+    { op: "replace", path: "/a", value: 3 },
+    { op: "remove", path: "/b" },
+    { op: "add", path: "/c", value: 4 },
+  ]);
+  assertEquals(diff([1, 2, 3], [1, 3, 4]), [
+    { op: "remove", path: "/1" },
+    { op: "add", path: "/2", value: 4 },
+  ]);
+  assertEquals(diff({ "a/b": [1] }, { "a/b": [2] }), [
+    { op: "replace", path: "/a~1b/0", value: 2 },
+  ]);
+});
+
+Deno.test("diff - move detection", () => {
+  const a = ["x", { n: 1 }, { n: 2 }];
+  const b = [{ n: 1 }, { n: 2 }, "x"];
+  assertEquals(diff(a, b), [
+    { op: "remove", path: "/0" },
+    { op: "add", path: "/2", value: "x" },
+  ]);
+  assertEquals(diff(a, b, { detectMoves: true }), [
+    { op: "move", from: "/0", path: "/2" },
+  ]);
+});
+
+Deno.test("diff - RootNode inputs can be applied as a patch", () => {
+  const root = parse(`{
+  // comment
+  "a": 1,
+  "b": [1, 2]
+}`);
+  const target = { a: 2, b: [1, 2, 3], c: true };
+  const operations = diff(root, target);
+  assertEquals(operations, [
+    { op: "replace", path: "/a", value: 2 },
+    { op: "add", path: "/b/2", value: 3 },
+    { op: "add", path: "/c", value: true },
+  ]);
+  assertEquals(diff(root, parse(JSON.stringify(root.toValue()))), []);
+
+  root.applyPatch(operations);
+  assertEquals(root.toValue(), target);
+  assertEquals(root.toString().includes("// comment"), true);
+
+  // documents compare numbers with more precision than JavaScript has
+  assertEquals(
+    diff(parse("[9007199254740993]"), parse("[9007199254740992]")),
+    [{ op: "replace", path: "/0", value: 9007199254740992 }],
+  );
+  assertEquals(diff(parse(""), null), []);
+  // deno-lint-ignore no-explicit-any
+  assertThrows(() => diff(parse("{}"), Symbol("value") as any));
+});
+
+Deno.test("ObjectProp - rename keeps quote style and comments", () => {
+  const root = parse(`{
+  // comment
+  "a": /* inner */ [1, 2],
+  'b': 1,
+  c: true,
+}`);
+  const obj = root.asObjectOrThrow();
+  obj.getOrThrow("a").rename("renamed");
+  obj.getOrThrow("b").rename(`it's`);
+  obj.getOrThrow("c").rename("d");
+  assertEquals(
+    root.toString(),
+    `{
+  // comment
+  "renamed": /* inner */ [1, 2],
+  'it\\'s': 1,
+  d: true,
+}`,
+  );
+  assertEquals(root.toValue(), { renamed: [1, 2], "it's": 1, d: true });
+});
+
+Deno.test("ObjectPropName - setValue quotes names that aren't identifiers", () => {
+  const root = parse(`{ a: 1 }`);
+  const prop = root.asObjectOrThrow().getOrThrow("a");
+  const name = prop.nameOrThrow().setValue("my key");
+  assertEquals(name.decodedValue(), "my key");
+  assertEquals(root.toString(), `{ "my key": 1 }`);
+  assertEquals(prop.nameOrThrow().setValue("a-b").decodedValue(), "a-b");
+  assertEquals(root.toString(), `{ "a-b": 1 }`);
+
+  const dollar = parse(`{ a: 1 }`);
+  dollar.asObjectOrThrow().getOrThrow("a").rename("$a$");
+  assertEquals(dollar.toString(), `{ $a$: 1 }`);
+});
+
+Deno.test("Comments - leading, trailing and inner comments", () => {
+  const root = parse(`{
+  // line comment
+  /**
+   * Doc comment.
+   */
+  "a" /* name */: [1, /* before */ 2 /* after */, // end of line
+  ], // trailing
+  "b": {
+    // inside
+  }
+}`);
+  const texts = (comments: { text: string }[]) => comments.map((c) => c.text);
+  const obj = root.asObjectOrThrow();
+  const a = obj.getOrThrow("a");
+  assertEquals(texts(a.leadingComments()), ["line comment", "Doc comment."]);
+  assertEquals(texts(a.trailingComments()), ["trailing"]);
+  assertEquals(texts(a.innerComments()), ["name"]);
+  assertEquals(a.leadingComments()[0].kind, "line");
+  assertEquals(a.leadingComments()[0].raw, "// line comment");
+  assertEquals(a.leadingComments()[1].kind, "block");
+  // a property's value uses the property's comments
+  assertEquals(texts(a.valueOrThrow().leadingComments()), [
+    "line comment",
+    "Doc comment.",
+  ]);
+
+  const elements = a.valueIfArrayOrThrow().elements();
+  assertEquals(texts(elements[0].leadingComments()), []);
+  assertEquals(texts(elements[0].trailingComments()), []);
+  assertEquals(texts(elements[1].leadingComments()), ["before"]);
+  assertEquals(texts(elements[1].trailingComments()), [
+    "after",
+    "end of line",
+  ]);
+
+  const b = obj.getIfObjectOrThrow("b");
+  assertEquals(texts(b.innerComments()), ["inside"]);
+  assertEquals(texts(obj.getOrThrow("b").leadingComments()), []);
+});
+
+Deno.test("Comments - set and remove comments", () => {
+  const root = parse(`// header
+{
+  // old
+  "a": [1, /* old */ 2], // old
+  "b": 1 /* old */,
+  "c": 2
+}
+`);
+  const obj = root.asObjectOrThrow();
+  const a = obj.getOrThrow("a");
+  a.setLeadingComment("first\nsecond", { kind: "block" });
+  a.setTrailingComment("added by tool");
+  a.valueIfArrayOrThrow().elements()[1].setLeadingComment("element", {
+    kind: "block",
+  });
+  root.setLeadingComment("new header");
+  assertEquals(
+    root.toString(),
+    `// new header
+{
+  /**
+   * first
+   * second
+   */
+  "a": [1, /* element */ 2], // added by tool
+  "b": 1 /* old */,
+  "c": 2
+}
+`,
+  );
+  assertEquals(a.leadingComments()[0].text, "first\nsecond");
+
+  // comments are added where there are none
+  const c = obj.getOrThrow("c");
+  c.setLeadingComment("added");
+  c.setTrailingComment("also added");
+  assertEquals(c.leadingComments().map((x) => x.text), ["added"]);
+  assertEquals(c.trailingComments().map((x) => x.text), ["also added"]);
+  // a line comment would comment out the element after it
+  assertThrows(() =>
+    a.valueIfArrayOrThrow().elements()[0].setLeadingComment("text")
+  );
+  // a line comment would comment out the comma
+  assertThrows(() => obj.getOrThrow("b").setTrailingComment("text"));
+  assertThrows(() =>
+    obj.getOrThrow("b").setTrailingComment("*/", { kind: "block" })
+  );
+
+  a.removeComments();
+  obj.getOrThrow("b").removeComments();
+  a.valueIfArrayOrThrow().elements()[1].removeComments();
+  c.removeComments();
+  root.removeComments();
+  assertEquals(
+    root.toString(),
+    `{
+  "a": [1, 2],
+  "b": 1,
+  "c": 2
+}
+`,
+  );
+});
+
+Deno.test("JsonObject - sortProperties", () => {
+  const root = parse(`{
+  "imports": {
+    "c": "./c.ts",
+    // keep this one
+    "a": "./a.ts",
+    "b": 'b.ts'
+  },
+  "name": "pkg"
+}`);
+  root.asObjectOrThrow().sortProperties(undefined, { sortKeysDeep: true });
+  assertEquals(
+    root.toString(),
+    `{
+  "imports": {
+    // keep this one
+    "a": "./a.ts",
+    "b": 'b.ts',
+    "c": "./c.ts"
+  },
+  "name": "pkg"
+}`,
+  );
+
+  const obj = parse(`{ "a": 1, "b": 2, "c": 3 }`).asObjectOrThrow();
+  obj.sortProperties((a, b) => b.localeCompare(a));
+  assertEquals(obj.properties().map((p) => p.nameOrThrow().decodedValue()), [
+    "c",
+    "b",
+    "a",
+  ]);
+});
+
+Deno.test("JsonObject - sortProperties moves comments with properties", () => {
+  const root = parse(`{
+  // about b
+  "b": 1,
+  // about a
+  "a": 2 // a note
+}`);
+  root.asObjectOrThrow().sortProperties();
+  assertEquals(
+    root.toString(),
+    `{
+  // about a
+  "a": 2, // a note
+  // about b
+  "b": 1
+}`,
+  );
+});
+
+Deno.test("ObjectProp - moveTo keeps comments", () => {
+  const root = parse(`{
+  "a": 1,
+  // about b
+  "b": {
+    // inner
+    "x": 1
+  },
+  "c": 3
+}`);
+  const obj = root.asObjectOrThrow();
+  const prop = obj.getOrThrow("b").moveTo(2);
+  assertEquals(prop.propertyIndex(), 2);
+  assertEquals(
+    root.toString(),
+    `{
+  "a": 1,
+  "c": 3,
+  // about b
+  "b": {
+    // inner
+    "x": 1
+  }
+}`,
+  );
+  assertThrows(() => obj.getOrThrow("a").moveTo(3));
+});
+
+Deno.test("Node - moveTo array elements", () => {
+  const root = parse(`[
+  1,
+  2, // two
+  3
+]`);
+  const arr = root.asArrayOrThrow();
+  arr.elements()[2].moveTo(0);
+  assertEquals(
+    root.toString(),
+    `[
+  3,
+  1,
+  2 // two
+]`,
+  );
+
+  const single = parse("[1, 2, 3]");
+  single.asArrayOrThrow().elements()[0].moveTo(2);
+  assertEquals(single.toString(), "[2, 3, 1]");
+});
+
+Deno.test("ObjectProp - moveToObject", () => {
+  const root = parse(`{
+  "a": {
+    "x": 1,
+    "y": 2
+  },
+  "b": {
+    "z": 3
+  }
+}`);
+  const obj = root.asObjectOrThrow();
+  const y = obj.getIfObjectOrThrow("a").getOrThrow("y");
+  const prop = y.moveToObject(obj.getIfObjectOrThrow("b"), 0);
+  assertEquals(prop.propertyIndex(), 0);
+  assertEquals(
+    root.toString(),
+    `{
+  "a": {
+    "x": 1
+  },
+  "b": {
+    "y": 2,
+    "z": 3
+  }
+}`,
+  );
+
+  const other = parse(`{
+  // comment
+  "c": 1
+}`);
+  const c = other.asObjectOrThrow().getOrThrow("c");
+  c.moveToObject(obj.getIfObjectOrThrow("a"), 1);
+  assertEquals(other.toString(), "{}");
+  assertEquals(
+    root.toString(),
+    `{
+  "a": {
+    "x": 1,
+    // comment
+    "c": 1
+  },
+  "b": {
+    "y": 2,
+    "z": 3
+  }
+}`,
+  );
+  // a property can't be moved into its own value
+  assertThrows(() =>
+    obj.getOrThrow("a").moveToObject(obj.getIfObjectOrThrow("a"), 0)
+  );
+});
+
+Deno.test("Node - clone and insert nodes from another document", () => {
+  const template = parse(`{
+  "name": 'template',
+  "tags": ["a", 'b'],
+  // comment
+  "version": 0x10,
+  "nested": {
+    // inner
+    "a": 1
+  }
+}`);
+  const templateObj = template.asObjectOrThrow();
+  const root = parse(`{
+  "items": []
+}`);
+  const obj = root.asObjectOrThrow();
+
+  obj.append("tags", templateObj.getOrThrow("tags").valueOrThrow());
+  const name = templateObj.getOrThrow("name").valueOrThrow().clone();
+  assertEquals(name.parent(), undefined);
+  obj.getIfArrayOrThrow("items").append(name);
+  obj.insert(0, "version", templateObj.getOrThrow("version"));
+  obj.append("nested", templateObj.getIfObjectOrThrow("nested"));
+  assertEquals(
+    root.toString(),
+    `{
+  // comment
+  "version": 0x10,
+  "items": ['template'],
+  "tags": ["a", 'b'],
+  "nested": {
+    // inner
+    "a": 1
+  }
+}`,
+  );
+  // the template is left as is
+  assertEquals(templateObj.properties().length, 4);
+});
+
+Deno.test("Raw text - appendRaw, insertRaw and setValueRaw", () => {
+  const root = parse(`{
+  "items": [1],
+  "b": 'a'
+}`);
+  const obj = root.asObjectOrThrow();
+  obj.getIfArrayOrThrow("items").insertRaw(0, "1.50");
+  obj.getOrThrow("b").setValueRaw("'s'");
+  obj.appendRaw("raw", `{ 'x': 1.0, "y": [0xFF] }`, {
+    allowHexadecimalNumbers: true,
+  });
+  assertEquals(
+    root.toString(),
+    `{
+  "items": [1.50, 1],
+  "b": 's',
+  "raw": { 'x': 1.0, "y": [0xFF] }
+}`,
+  );
+
+  obj.appendRaw("c", "// list\n[\n  1, // one\n  2\n]");
+  assertEquals(
+    root.toString(),
+    `{
+  "items": [1.50, 1],
+  "b": 's',
+  "raw": { 'x': 1.0, "y": [0xFF] },
+  // list
+  "c": [
+    1, // one
+    2
+  ]
+}`,
+  );
+  assertThrows(
+    () => obj.appendRaw("d", "0xFF", { allowHexadecimalNumbers: false }),
+    ParseError,
+  );
+
+  // defaults to the options the document was parsed with
+  const strict = parseStrict(`{ "a": 1 }`).asObjectOrThrow();
+  assertThrows(() => strict.appendRaw("b", "'s'"), ParseError);
+  assertThrows(() => strict.appendRaw("b", "+1"), ParseError);
+  strict.appendRaw("b", "1.0");
+  assertEquals(strict.toString(), `{
+  "a": 1,
+  "b": 1.0
+}`);
+});
+
+Deno.test("RootNode - toString with format options", () => {
+  const text = `{
+    "a": 1, // one
+
+
+    'b': [1,2,   3],
+    "c": {"x": 'y'},
+}
+`;
+  const root = parse(text);
+  assertEquals(root.toString(), text);
+  // these don't move anything to another line
+  assertEquals(
+    root.toString({ indentWidth: 2 }),
+    text.replaceAll("    ", "  "),
+  );
+  assertEquals(root.toString({ useTabs: true }), text.replaceAll("    ", "\t"));
+  assertEquals(
+    root.toString({ newlineKind: "\r\n" }),
+    text.replaceAll("\n", "\r\n"),
+  );
+  assertEquals(
+    root.toString({ quoteStyle: "single" }),
+    `{
+    'a': 1, // one
+
+
+    'b': [1,2,   3],
+    'c': {'x': 'y'},
+}
+`,
+  );
+  assertEquals(
+    root.toString({ quoteStyle: "double", trailingCommas: false }),
+    `{
+    "a": 1, // one
+
+
+    "b": [1,2,   3],
+    "c": {"x": "y"}
+}
+`,
+  );
+  assertEquals(root.toString({ trailingCommas: true }), text);
+  // these reflow the document
+  assertEquals(
+    root.toString({ lineWidth: 20 }),
+    `{
+    "a": 1, // one
+
+
+    'b': [1, 2, 3],
+    "c": {
+        "x": 'y'
+    },
+}
+`,
+  );
+  assertEquals(
+    root.toString({ preferSingleLine: true }),
+    `{
+    "a": 1, // one
+
+
+    'b': [1, 2, 3],
+    "c": { "x": 'y' },
+}
+`,
+  );
+  assertEquals(
+    root.toString({ maxBlankLines: 0 }),
+    `{
+    "a": 1, // one
+    'b': [1, 2, 3],
+    "c": { "x": 'y' },
+}
+`,
+  );
+  assertEquals(
+    root.toString({ indentWidth: 2, lineWidth: 10, newlineKind: "\r\n" }),
+    [
+      "{",
+      '  "a": 1, // one',
+      "",
+      "",
+      "  'b': [",
+      "    1,",
+      "    2,",
+      "    3",
+      "  ],",
+      '  "c": {',
+      "    \"x\": 'y'",
+      "  },",
+      "}",
+      "",
+    ].join("\r\n"),
+  );
+  // the document itself isn't changed
+  assertEquals(root.toString(), text);
+  assertThrows(() => root.toString({ quoteStyle: "backtick" as "single" }));
+  assertThrows(() => root.toString({ indentWidth: 2.5 }));
+  assertThrows(() => root.toString({ indentWidth: -1 }));
+  assertThrows(() => root.toString({ lineWidth: Infinity }));
+  assertThrows(() => root.toString({ maxBlankLines: 1e12 }));
+});
+
+Deno.test("format - reformats the whole document", () => {
+  const text = [
+    "// header",
+    "",
+    "",
+    "",
+    "{",
+    "        /**",
+    "         * Doc",
+    "         */",
+    '        "a": [',
+    "   1, 2],",
+    '  "b": {"c": [1,',
+    ' 2]}, "d": {',
+    '"e": true /* x */}',
+    "}",
+  ].join("\n");
+  const expected = `// header
+
+{
+  /**
+   * Doc
+   */
+  "a": [
+    1,
+    2
+  ],
+  "b": { "c": [1, 2] },
+  "d": {
+    "e": true /* x */
+  }
+}
+`;
+  assertEquals(format(text), expected);
+  const root = parse(text);
+  assertEquals(root.format(), expected);
+  // the document itself isn't changed
+  assertEquals(root.toString(), text);
+  assertEquals(
+    root.format({ preferSingleLine: true, maxBlankLines: 2, indentWidth: 4 }),
+    `// header
+
+
+{
+    /**
+     * Doc
+     */
+    "a": [1, 2],
+    "b": { "c": [1, 2] },
+    "d": { "e": true /* x */ }
+}
+`,
+  );
+  assertEquals(
+    format('{"b": {"c": [1, 2]}}', { lineWidth: 10 }),
+    `{
+  "b": {
+    "c": [
+      1,
+      2
+    ]
+  }
+}
+`,
+  );
+  assertThrows(() => format("{"), ParseError);
+});
+
+Deno.test("format - keeps comments when collapsing", () => {
+  const text = `{
+  "a": [
+    1, /* one */
+    2
+  ],
+  "b": [
+    1, // one
+    2
+  ],
+  /* c */ "c": {
+    "d": true
+  } /* after */
+}`;
+  // line comments keep their object or array on multiple lines
+  assertEquals(
+    format(text, { preferSingleLine: true }),
+    `{
+  "a": [1 /* one */, 2],
+  "b": [
+    1, // one
+    2
+  ],
+  /* c */ "c": { "d": true } /* after */
+}
+`,
+  );
+});
+
+Deno.test("format - lineWidth", () => {
+  const text = '{ "a": [1, 2], "b": { "c": "d" } }';
+  // the line is 34 characters
+  assertEquals(format(text, { lineWidth: 34 }), text + "\n");
+  assertEquals(
+    format(text, { lineWidth: 33 }),
+    `{
+  "a": [1, 2],
+  "b": { "c": "d" }
+}
+`,
+  );
+  // tabs count as the indentWidth
+  const tabs = '{\n\t"a": [\n\t\t1,\n\t\t2\n\t]\n}';
+  const options = { useTabs: true, preferSingleLine: true, lineWidth: 14 };
+  assertEquals(format(tabs, options), '{\n\t"a": [1, 2]\n}\n');
+  assertEquals(format(tabs, { ...options, indentWidth: 4 }), tabs + "\n");
+});
+
+Deno.test("format - quoteStyle", () => {
+  const text = `{ "a": 'it\\'s', 'b': "say \\"hi\\"", c: "plain" }`;
+  assertEquals(
+    format(text, { quoteStyle: "single" }),
+    `{ 'a': 'it\\'s', 'b': 'say "hi"', c: 'plain' }\n`,
+  );
+  assertEquals(
+    format(text, { quoteStyle: "double" }),
+    `{ "a": "it's", "b": "say \\"hi\\"", c: "plain" }\n`,
+  );
+  assertEquals(format(text, { quoteStyle: "preserve" }), text + "\n");
+  assertThrows(() => format(text, { quoteStyle: "backtick" as "single" }));
+});
+
 Deno.test("Children access - root", () => {
   const text = '{"a": 1, "b": 2}';
   const root = parse(text);
@@ -575,6 +1710,63 @@ Deno.test("JsonArray - ensureMultiline formats array", () => {
   assertEquals(output.includes("\n"), true);
 });
 
+Deno.test("JsonArray - ensureSingleLine collapses array", () => {
+  const root = parse(`{
+  "lib": ["deno.ns"]
+}`);
+  const lib = root.asObjectOrThrow().getIfArrayOrThrow("lib");
+  lib.append("dom");
+  lib.ensureMultiline();
+  assertEquals(lib.isMultiline(), true);
+
+  lib.ensureSingleLine();
+  assertEquals(lib.isMultiline(), false);
+  assertEquals(
+    root.toString(),
+    `{
+  "lib": ["deno.ns", "dom"]
+}`,
+  );
+});
+
+Deno.test("JsonObject - ensureSingleLine collapses nested values", () => {
+  const root = parse(`{
+  "a": {
+    "lib": [
+      "deno.ns",
+      "dom",
+    ],
+    "b":
+      /* c */ {
+        "x": 1
+      }
+  }
+}`);
+  const a = root.asObjectOrThrow().getIfObjectOrThrow("a");
+  assertEquals(root.asObjectOrThrow().isMultiline(), true);
+  a.ensureSingleLine();
+  assertEquals(
+    root.toString(),
+    `{
+  "a": { "lib": ["deno.ns", "dom"], "b": /* c */ { "x": 1 } }
+}`,
+  );
+  assertEquals(a.isMultiline(), false);
+});
+
+Deno.test("JsonObject - ensureSingleLine throws with line comments", () => {
+  const text = `{
+  "a": 1 // comment
+}`;
+  const root = parse(text);
+  assertThrows(
+    () => root.asObjectOrThrow().ensureSingleLine(),
+    Error,
+    "line comment",
+  );
+  assertEquals(root.toString(), text);
+});
+
 Deno.test("JsonObjectProp - objectValueOrForce on property", () => {
   const text = '{"user": null}';
   const root = parse(text);
@@ -861,6 +2053,68 @@ Deno.test("RootNode - newlineKind detects newline type", () => {
   assertExists(newlineKind);
 });
 
+Deno.test("RootNode - setNewlineKind and setIndentText", () => {
+  const text = [
+    "{",
+    "  /**",
+    "   * Doc",
+    "   */",
+    '  "a": [',
+    "    1,",
+    "    2 // two",
+    "  ],",
+    '  "b": { "c":   1 }',
+    "}",
+    "",
+  ].join("\r\n");
+  const root = parse(text);
+  root.setNewlineKind("\n");
+  assertEquals(root.newlineKind(), "\n");
+  assertEquals(root.toString(), text.replaceAll("\r\n", "\n"));
+
+  assertEquals(root.setIndentText("\t"), true);
+  assertEquals(root.singleIndentText(), "\t");
+  assertEquals(
+    root.toString(),
+    `{
+\t/**
+\t * Doc
+\t */
+\t"a": [
+\t\t1,
+\t\t2 // two
+\t],
+\t"b": { "c":   1 }
+}
+`,
+  );
+
+  // inserted values use the new indentation
+  root.asObjectOrThrow().getOrThrow("a").valueIfArrayOrThrow().append(3);
+  root.setNewlineKind("\r\n");
+  assertEquals(
+    root.toString(),
+    [
+      "{",
+      "\t/**",
+      "\t * Doc",
+      "\t */",
+      '\t"a": [',
+      "\t\t1,",
+      "\t\t2, // two",
+      "\t\t3",
+      "\t],",
+      '\t"b": { "c":   1 }',
+      "}",
+      "",
+    ].join("\r\n"),
+  );
+
+  assertEquals(parse("[1]").setIndentText("  "), false);
+  assertThrows(() => root.setIndentText("x"));
+  assertThrows(() => root.setNewlineKind("\r" as "\n"));
+});
+
 // Value conversion tests
 Deno.test("setValue - accepts object values", () => {
   const root = parse("null");
@@ -1648,6 +2902,16 @@ Deno.test("Node.toValue - null node", () => {
   assertEquals(result, null);
 });
 
+Deno.test("Node.toValue - parses with the document's options", () => {
+  const root = parse(`{ "a": "b" }`, { allowSingleQuotedStrings: false });
+  const prop = root.asObjectOrThrow().getOrThrow("a");
+  // property names aren't values
+  assertEquals(prop.children()[0].toValue(), undefined);
+  const value = prop.valueOrThrow();
+  value.asStringLitOrThrow().setRawValue("'c'");
+  assertThrows(() => value.toValue(), ParseError);
+});
+
 Deno.test("parseToValue - empty object", () => {
   const text = "{}";
   const result = parseToValue(text);
@@ -1818,6 +3082,109 @@ Deno.test("parseToValue - allowUnaryPlusNumbers option", () => {
   assertEquals(result.value, 100);
 });
 
+Deno.test("parseToValue - largeIntegers option", () => {
+  const text = '{"id": 1234567890123456789, "small": 5, "float": 1.5}';
+  assertEquals(parseToValue(text), {
+    id: 1234567890123456800,
+    small: 5,
+    float: 1.5,
+  });
+  assertEquals(parseToValue(text, { largeIntegers: "bigint" }), {
+    id: 1234567890123456789n,
+    small: 5,
+    float: 1.5,
+  });
+  assertEquals(parseToValue(text, { largeIntegers: "string" }), {
+    id: "1234567890123456789",
+    small: 5,
+    float: 1.5,
+  });
+  assertEquals(
+    parseToValue("[-0x7FFFFFFFFFFFFFFFFF]", { largeIntegers: "bigint" }),
+    [-0x7FFFFFFFFFFFFFFFFFn],
+  );
+  assertThrows(() => parseToValue(text, { largeIntegers: "big" as "bigint" }));
+});
+
+Deno.test("toValue - largeIntegers option", () => {
+  const root = parse('{"ids": [18446744073709551615, 1]}');
+  assertEquals(root.toValue({ largeIntegers: "bigint" }), {
+    ids: [18446744073709551615n, 1],
+  });
+  const ids = root.asObjectOrThrow().getOrThrow("ids");
+  assertEquals(ids.toValue({ largeIntegers: "string" }), [
+    "18446744073709551615",
+    1,
+  ]);
+});
+
+Deno.test("setValue - accepts BigInt values", () => {
+  const root = parse("{}");
+  const obj = root.asObjectOrThrow();
+  obj.append("id", 1234567890123456789n);
+  obj.append("ids", [-9223372036854775808n, 18446744073709551615n]);
+  obj.getOrThrow("id").setValue(9007199254740993n);
+  assertEquals(
+    root.toString(),
+    '{\n  "id": 9007199254740993,\n  "ids": [-9223372036854775808, 18446744073709551615]\n}',
+  );
+  assertEquals(root.toValue({ largeIntegers: "bigint" }), {
+    id: 9007199254740993n,
+    ids: [-9223372036854775808n, 18446744073709551615n],
+  });
+});
+
+Deno.test("NumberLit - asNumber and asBigInt", () => {
+  const root = parse("[0xFF, 1.5, 1e3, 18446744073709551615]");
+  const numbers = root.asArrayOrThrow().elements().map((e) =>
+    e.asNumberLitOrThrow()
+  );
+  assertEquals(numbers.map((n) => n.asNumber()), [
+    255,
+    1.5,
+    1000,
+    18446744073709551615,
+  ]);
+  assertEquals(numbers[0].asBigInt(), 255n);
+  assertEquals(numbers[2].asBigInt(), 1000n);
+  assertEquals(numbers[3].asBigInt(), 18446744073709551615n);
+  assertThrows(() => numbers[1].asBigInt(), Error, "Expected an integer");
+});
+
+Deno.test("NumberLit - setNumber", () => {
+  const root = parse(
+    '{"hex": 0xFF, "exp": 1E+3, "decimal": 1.50, "plus": +1, "plain": 1.50}',
+  );
+  const obj = root.asObjectOrThrow();
+  const numberLit = (name: string) =>
+    obj.getOrThrow(name).valueOrThrow().asNumberLitOrThrow();
+
+  numberLit("hex").setNumber(4096, { preserveStyle: true });
+  numberLit("exp").setNumber(2500, { preserveStyle: true });
+  numberLit("decimal").setNumber(2, { preserveStyle: true });
+  numberLit("plus").setNumber(9007199254740993n, { preserveStyle: true });
+  numberLit("plain").setNumber(2);
+  assertEquals(
+    root.toString(),
+    '{"hex": 0x1000, "exp": 2.5E+3, "decimal": 2.00, "plus": +9007199254740993, "plain": 2}',
+  );
+
+  assertThrows(() => numberLit("plain").setNumber(NaN), Error, "NaN");
+  assertThrows(() => numberLit("plain").setNumber(Infinity), Error, "finite");
+  assertEquals(numberLit("plain").asNumber(), 2);
+});
+
+Deno.test("NumberLit - setRawValue validates the value", () => {
+  const root = parse("[1]");
+  const lit = root.asArrayOrThrow().elements()[0].asNumberLitOrThrow();
+  lit.setRawValue("0x1F");
+  assertEquals(root.toString(), "[0x1F]");
+  for (const value of ["NaN", "1.", "abc", "1, 2", " 1"]) {
+    assertThrows(() => lit.setRawValue(value), Error, "Expected a number");
+  }
+  assertEquals(root.toString(), "[0x1F]");
+});
+
 Deno.test("parse - all new options combined", () => {
   const text = `{
     'name': 'test'
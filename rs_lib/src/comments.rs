@@ -0,0 +1,662 @@
+use std::fmt;
+
+use jsonc_parser::cst::CstComment;
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstNewline;
+use jsonc_parser::cst::CstNewlineKind;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstWhitespace;
+use serde::Serialize;
+
+use crate::position;
+use crate::position::TextRange;
+use crate::trivia;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentKind {
+  /// A `// ...` comment.
+  Line,
+  /// A `/* ... */` comment.
+  Block,
+}
+
+/// Information about a comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CommentInfo {
+  pub kind: CommentKind,
+  /// Text of the comment without the delimiters or surrounding whitespace.
+  pub text: String,
+  /// Text of the comment including the delimiters.
+  pub raw: String,
+  /// Byte range of the comment in the document's text.
+  pub range: TextRange,
+}
+
+impl CommentInfo {
+  pub fn new(comment: &CstComment) -> Self {
+    let raw = comment.raw_value();
+    let (kind, text) = if comment.is_line_comment() {
+      (CommentKind::Line, raw[2..].trim().to_string())
+    } else {
+      let end = raw.len().saturating_sub(2).max(2);
+      (CommentKind::Block, block_comment_text(&raw[2..end]))
+    };
+    Self {
+      kind,
+      text,
+      raw: raw.clone(),
+      range: position::node_range(&comment.clone().into()),
+    }
+  }
+}
+
+/// Error that occurs when a comment can't be written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentError {
+  /// The text can't be written as a comment of the kind.
+  InvalidText { kind: CommentKind, message: String },
+  /// The node isn't a value, property or array element, so it can't have
+  /// comments.
+  NotCommentable,
+  /// A line comment would comment out the rest of the line.
+  LineCommentNotAtEndOfLine,
+}
+
+impl fmt::Display for CommentError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      CommentError::InvalidText { kind, message } => {
+        let kind = match kind {
+          CommentKind::Line => "line",
+          CommentKind::Block => "block",
+        };
+        write!(f, "Invalid {} comment text: {}", kind, message)
+      }
+      CommentError::NotCommentable => write!(
+        f,
+        "Expected a value, property or array element to add a comment to"
+      ),
+      CommentError::LineCommentNotAtEndOfLine => write!(
+        f,
+        "Cannot use a line comment here because it would comment out the \
+         rest of the line"
+      ),
+    }
+  }
+}
+
+/// Gets the comments before the node that aren't trailing comments of the
+/// previous element.
+///
+/// Comments of a property's name or value are the property's comments.
+pub fn leading(node: &CstNode) -> Vec<CstComment> {
+  let node = subject(node);
+  if !is_element(&node) {
+    return Vec::new();
+  }
+  let mut comments = Vec::new();
+  let mut previous_element = None;
+  for sibling in node.previous_siblings() {
+    if let Some(comment) = sibling.as_comment() {
+      comments.push(comment);
+    } else if !sibling.is_trivia() {
+      previous_element = if sibling.is_comma() {
+        sibling.previous_siblings().find(is_element)
+      } else if is_element(&sibling) {
+        Some(sibling)
+      } else {
+        None
+      };
+      break;
+    }
+  }
+  if let Some(previous_element) = previous_element {
+    let claimed = trailing_of(&previous_element);
+    comments.retain(|c| !claimed.iter().any(|t| is_same(c, t)));
+  }
+  comments.reverse();
+  comments
+}
+
+/// Gets the comments after the node on the same line, including those after
+/// its comma unless they precede the next element on that line.
+///
+/// Comments of a property's name or value are the property's comments.
+pub fn trailing(node: &CstNode) -> Vec<CstComment> {
+  let node = subject(node);
+  if !is_element(&node) {
+    return Vec::new();
+  }
+  trailing_of(&node)
+}
+
+/// Gets the comments within the node that aren't leading or trailing
+/// comments of one of its children (ex. comments in an empty object, or
+/// between a property's name and value).
+pub fn inner(node: &CstNode) -> Vec<CstComment> {
+  let children = node.children();
+  if node.as_object_prop().is_some() {
+    return children.iter().filter_map(|c| c.as_comment()).collect();
+  }
+  let claimed = children
+    .iter()
+    .filter(|c| is_element(c))
+    .flat_map(|c| leading(c).into_iter().chain(trailing(c)))
+    .collect::<Vec<_>>();
+  children
+    .iter()
+    .filter_map(|c| c.as_comment())
+    .filter(|c| !claimed.iter().any(|t| is_same(c, t)))
+    .collect()
+}
+
+/// Gets if there are any comments within the node.
+pub fn contains_comment(node: &CstNode) -> bool {
+  node
+    .children()
+    .iter()
+    .any(|child| child.is_comment() || contains_comment(child))
+}
+
+/// Replaces the node's leading comments with a single comment, or adds one
+/// when it has none.
+///
+/// An added comment goes on its own line above the node when the node starts
+/// its line. Otherwise, it goes before the node on the same line, which
+/// requires a block comment.
+pub fn set_leading(
+  node: &CstNode,
+  text: &str,
+  kind: CommentKind,
+) -> Result<(), CommentError> {
+  let comments = leading(node);
+  if !comments.is_empty() {
+    return replace(node, comments, text, kind);
+  }
+  let subject = subject(node);
+  let Some(parent) = subject.parent().filter(|_| is_element(&subject)) else {
+    return Err(CommentError::NotCommentable);
+  };
+  if kind == CommentKind::Line && !starts_line(&subject) {
+    return Err(CommentError::LineCommentNotAtEndOfLine);
+  }
+  let (indent, newline_kind) = line_info(&subject);
+  let comment = CstComment::new(format_comment(
+    text,
+    kind,
+    &indent,
+    newline_text(newline_kind),
+  )?);
+  insert_leading(&subject, &parent, vec![(comment, true)]);
+  Ok(())
+}
+
+/// Replaces the node's trailing comments with a single comment, or adds one
+/// when it has none.
+///
+/// An added comment goes after the node's comma when nothing else follows on
+/// the line, and before it otherwise, which requires a block comment.
+pub fn set_trailing(
+  node: &CstNode,
+  text: &str,
+  kind: CommentKind,
+) -> Result<(), CommentError> {
+  let mut comments = trailing(node);
+  if !comments.is_empty() {
+    // the last trailing comment is the one that ends the line
+    comments.reverse();
+    return replace(node, comments, text, kind);
+  }
+  let subject = subject(node);
+  let Some(parent) = subject.parent().filter(|_| is_element(&subject)) else {
+    return Err(CommentError::NotCommentable);
+  };
+  if kind == CommentKind::Line && !is_end_of_line(&trailing_anchor(&subject)) {
+    return Err(CommentError::LineCommentNotAtEndOfLine);
+  }
+  let (indent, newline_kind) = line_info(&subject);
+  let comment = CstComment::new(format_comment(
+    text,
+    kind,
+    &indent,
+    newline_text(newline_kind),
+  )?);
+  insert_trailing(&subject, &parent, vec![comment]);
+  Ok(())
+}
+
+/// Removes the node's leading and trailing comments.
+pub fn remove_all(node: &CstNode) {
+  for comment in leading(node).into_iter().chain(trailing(node)) {
+    remove(comment);
+  }
+}
+
+/// Removes the comment along with the whitespace and newline that would be
+/// left behind.
+pub fn remove(comment: CstComment) {
+  let node: CstNode = comment.clone().into();
+  if is_end_of_line(&node) {
+    let previous = node.previous_siblings().find(|n| !n.is_whitespace());
+    let whitespace = node
+      .previous_siblings()
+      .take_while(|n| n.is_whitespace())
+      .collect::<Vec<_>>();
+    for sibling in whitespace {
+      sibling.remove();
+    }
+    // remove the line when the comment was on its own line
+    match previous {
+      Some(previous) if previous.is_newline() => previous.remove(),
+      Some(_) => {}
+      None => {
+        if let Some(next) = node.next_siblings().find(|n| n.is_newline()) {
+          next.remove();
+        }
+      }
+    }
+  } else {
+    // keep the whitespace on one side of the comment, which is the
+    // indentation when the comment starts its line
+    let previous_whitespace = node
+      .previous_siblings()
+      .take_while(|n| n.is_whitespace())
+      .collect::<Vec<_>>();
+    let whitespace = if previous_whitespace.is_empty() || starts_line(&node) {
+      node
+        .next_siblings()
+        .take_while(|n| n.is_whitespace())
+        .collect()
+    } else {
+      previous_whitespace
+    };
+    for sibling in whitespace {
+      sibling.remove();
+    }
+  }
+  // removing a line comment also removes the whitespace and newline before
+  // it, which was handled above, so remove it as a block comment instead
+  comment.set_raw_value("/**/".to_string());
+  comment.remove();
+}
+
+/// The leading and trailing comments of a property or array element, taken
+/// out of the document or copied so they can be attached to the node or its
+/// copy once it's inserted elsewhere.
+#[derive(Default)]
+pub(crate) struct DetachedComments {
+  /// The leading comments and whether each was on its own line.
+  leading: Vec<(CstComment, bool)>,
+  trailing: Vec<CstComment>,
+}
+
+impl DetachedComments {
+  /// Copies the node's leading and trailing comments.
+  pub(crate) fn copy(node: &CstNode) -> Self {
+    let detach = |comment: &CstComment| CstComment::new(comment.raw_value());
+    let leading = leading(node)
+      .iter()
+      .map(|c| (detach(c), is_end_of_line(&c.clone().into())))
+      .collect();
+    let trailing = trailing(node).iter().map(detach).collect();
+    Self { leading, trailing }
+  }
+
+  /// Copies the comments before and after the value of a root node, such as
+  /// one parsed from text to insert.
+  pub(crate) fn copy_around_root_value(value: &CstNode) -> Self {
+    let detach = |comment: CstComment| CstComment::new(comment.raw_value());
+    let mut leading = value
+      .previous_siblings()
+      .filter_map(|n| n.as_comment())
+      .map(|c| (detach(c.clone()), is_end_of_line(&c.into())))
+      .collect::<Vec<_>>();
+    leading.reverse();
+    let trailing = value
+      .next_siblings()
+      .filter_map(|n| n.as_comment())
+      .map(detach)
+      .collect();
+    Self { leading, trailing }
+  }
+
+  /// Removes the node's leading and trailing comments from the document.
+  pub(crate) fn remove(node: &CstNode) -> Self {
+    let comments = Self::copy(node);
+    remove_all(node);
+    comments
+  }
+
+  /// Gets if any of the comments is a line comment.
+  pub(crate) fn has_line_comment(&self) -> bool {
+    self.nodes().iter().any(|c| c.is_line_comment())
+  }
+
+  /// Gets the comment nodes, which aren't in a tree.
+  pub(crate) fn nodes(&self) -> Vec<CstComment> {
+    let leading = self.leading.iter().map(|(c, _)| c.clone());
+    leading.chain(self.trailing.iter().cloned()).collect()
+  }
+
+  /// Inserts the comments around the property or array element.
+  ///
+  /// Comments that were on their own line go on their own line again when
+  /// the node starts its line, and before the node otherwise.
+  pub(crate) fn attach(self, node: &CstNode) {
+    let Some(parent) = node.parent() else {
+      return;
+    };
+    insert_leading(node, &parent, self.leading);
+    insert_trailing(node, &parent, self.trailing);
+  }
+}
+
+/// Inserts comments before the node. Each comment goes on its own line when
+/// its flag is set and the node starts its line. A line comment is always
+/// followed by a newline so it doesn't comment out the node.
+fn insert_leading(
+  node: &CstNode,
+  parent: &CstContainerNode,
+  comments: Vec<(CstComment, bool)>,
+) {
+  let starts_line = starts_line(node);
+  let (indent, newline_kind) = line_info(node);
+  let mut nodes: Vec<CstNode> = Vec::new();
+  for (comment, own_line) in comments {
+    let line_comment = comment.is_line_comment();
+    nodes.push(comment.into());
+    if (starts_line && own_line) || line_comment {
+      nodes.extend(line_break(&indent, newline_kind));
+    } else {
+      nodes.push(CstWhitespace::new(" ".to_string()).into());
+    }
+  }
+  parent.insert_children(node.child_index(), nodes);
+}
+
+/// Inserts comments after the node on its line. A line comment is followed
+/// by a newline when another comment or something else follows, so it
+/// doesn't comment it out.
+fn insert_trailing(
+  node: &CstNode,
+  parent: &CstContainerNode,
+  comments: Vec<CstComment>,
+) {
+  let anchor = trailing_anchor(node);
+  let (indent, newline_kind) = line_info(node);
+  let mut nodes: Vec<CstNode> = Vec::new();
+  let mut after_line_comment = false;
+  for comment in comments {
+    if after_line_comment {
+      nodes.extend(line_break(&indent, newline_kind));
+    } else {
+      nodes.push(CstWhitespace::new(" ".to_string()).into());
+    }
+    after_line_comment = comment.is_line_comment();
+    nodes.push(comment.into());
+  }
+  if after_line_comment && !is_end_of_line(&anchor) {
+    let whitespace = anchor
+      .next_siblings()
+      .take_while(|n| n.is_whitespace())
+      .collect::<Vec<_>>();
+    for sibling in whitespace {
+      sibling.remove();
+    }
+    let (indent, newline_kind) = line_info(&parent.clone().into());
+    nodes.extend(line_break(&indent, newline_kind));
+  }
+  parent.insert_children(anchor.child_index() + 1, nodes);
+}
+
+/// Creates a newline followed by the indentation.
+fn line_break(indent: &str, newline_kind: CstNewlineKind) -> Vec<CstNode> {
+  let mut nodes = vec![CstNewline::new(newline_kind).into()];
+  if !indent.is_empty() {
+    nodes.push(CstWhitespace::new(indent.to_string()).into());
+  }
+  nodes
+}
+
+/// Gets the node that trailing comments are inserted after, which is the
+/// node's comma when nothing else follows on the line. Otherwise, a comment
+/// after the comma would be a leading comment of the next element on the
+/// same line.
+fn trailing_anchor(node: &CstNode) -> CstNode {
+  match node.trailing_comma().map(CstNode::from) {
+    Some(comma) if is_end_of_line(&comma) => comma,
+    _ => node.clone(),
+  }
+}
+
+/// Gets if only whitespace precedes the node on its line.
+fn starts_line(node: &CstNode) -> bool {
+  node
+    .previous_siblings()
+    .find(|n| !n.is_whitespace())
+    .is_none_or(|n| n.is_newline())
+}
+
+/// Replaces the first of the comments and removes the others.
+fn replace(
+  node: &CstNode,
+  comments: Vec<CstComment>,
+  text: &str,
+  kind: CommentKind,
+) -> Result<(), CommentError> {
+  let mut comments = comments.into_iter();
+  let Some(comment) = comments.next() else {
+    return Ok(());
+  };
+  if kind == CommentKind::Line && !is_end_of_line(&comment.clone().into()) {
+    return Err(CommentError::LineCommentNotAtEndOfLine);
+  }
+  let (indent, newline_kind) = line_info(&subject(node));
+  comment.set_raw_value(format_comment(
+    text,
+    kind,
+    &indent,
+    newline_text(newline_kind),
+  )?);
+  for comment in comments {
+    remove(comment);
+  }
+  Ok(())
+}
+
+/// Gets the indentation of the node's line and the document's newline kind.
+fn line_info(node: &CstNode) -> (String, CstNewlineKind) {
+  let indent = trivia::line_indent(node);
+  let newline_kind = node
+    .root_node()
+    .map(|r| r.newline_kind())
+    .unwrap_or_default();
+  (indent, newline_kind)
+}
+
+fn newline_text(kind: CstNewlineKind) -> &'static str {
+  match kind {
+    CstNewlineKind::LineFeed => "\n",
+    CstNewlineKind::CarriageReturnLineFeed => "\r\n",
+  }
+}
+
+/// Formats the text as a comment. Block comments with multiple lines are
+/// formatted as a doc comment with each line indented by the indent text.
+fn format_comment(
+  text: &str,
+  kind: CommentKind,
+  indent: &str,
+  newline: &str,
+) -> Result<String, CommentError> {
+  match kind {
+    CommentKind::Line => {
+      if text.contains(['\n', '\r']) {
+        return Err(CommentError::InvalidText {
+          kind,
+          message: "line comments cannot contain newlines".to_string(),
+        });
+      }
+      Ok(if text.is_empty() {
+        "//".to_string()
+      } else {
+        format!("// {}", text)
+      })
+    }
+    CommentKind::Block => {
+      if text.contains("*/") {
+        return Err(CommentError::InvalidText {
+          kind,
+          message: "block comments cannot contain '*/'".to_string(),
+        });
+      }
+      if !text.contains('\n') {
+        return Ok(format!("/* {} */", text));
+      }
+      let mut raw = "/**".to_string();
+      for line in text.lines() {
+        raw.push_str(newline);
+        raw.push_str(indent);
+        raw.push_str(" *");
+        if !line.is_empty() {
+          raw.push(' ');
+          raw.push_str(line);
+        }
+      }
+      raw.push_str(newline);
+      raw.push_str(indent);
+      raw.push_str(" */");
+      Ok(raw)
+    }
+  }
+}
+
+fn trailing_of(node: &CstNode) -> Vec<CstComment> {
+  let mut comments = Vec::new();
+  let mut after_comma = Vec::new();
+  let mut seen_comma = false;
+  for sibling in node.next_siblings() {
+    if let Some(comment) = sibling.as_comment() {
+      if seen_comma {
+        after_comma.push(comment);
+      } else {
+        comments.push(comment);
+      }
+    } else if sibling.is_comma() && !seen_comma {
+      seen_comma = true;
+    } else if sibling.is_newline() {
+      break;
+    } else if !sibling.is_whitespace() {
+      if is_element(&sibling) {
+        // these belong to the next element on the same line
+        after_comma.clear();
+      }
+      break;
+    }
+  }
+  comments.extend(after_comma);
+  comments
+}
+
+/// Gets the node whose comments should be used for the node.
+fn subject(node: &CstNode) -> CstNode {
+  match node.parent() {
+    Some(CstContainerNode::ObjectProp(prop)) => prop.into(),
+    _ => node.clone(),
+  }
+}
+
+fn is_element(node: &CstNode) -> bool {
+  !node.is_trivia() && node.as_token().is_none()
+}
+
+/// Gets if nothing but whitespace follows the node on its line.
+fn is_end_of_line(node: &CstNode) -> bool {
+  node
+    .next_siblings()
+    .find(|n| !n.is_whitespace())
+    .is_none_or(|n| n.is_newline())
+}
+
+fn is_same(a: &CstComment, b: &CstComment) -> bool {
+  a.child_index() == b.child_index()
+}
+
+/// Gets the text of a block comment, removing the leading `*` of each line
+/// in doc comments such as `/** ... */`.
+fn block_comment_text(inner: &str) -> String {
+  let Some(inner) = inner.strip_prefix('*') else {
+    return inner.trim().to_string();
+  };
+  let mut lines = inner.lines();
+  let first_line = lines.next().unwrap_or_default();
+  let other_lines = lines.map(|line| {
+    let line = line.trim_start();
+    let line = line.strip_prefix('*').unwrap_or(line);
+    line.strip_prefix(' ').unwrap_or(line)
+  });
+  std::iter::once(first_line.trim_start())
+    .chain(other_lines)
+    .collect::<Vec<_>>()
+    .join("\n")
+    .trim()
+    .to_string()
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use jsonc_parser::cst::CstRootNode;
+
+  use super::*;
+
+  fn parse_root(text: &str) -> CstRootNode {
+    CstRootNode::parse(text, &ParseOptions::default()).unwrap()
+  }
+
+  #[test]
+  fn adds_comments_to_properties() {
+    let root = parse_root("{\n  \"a\": 1,\n  \"b\": [1, 2]\n}");
+    let obj = root.object_value().unwrap();
+    let a: CstNode = obj.get("a").unwrap().into();
+    set_leading(&a, "added by tool X", CommentKind::Line).unwrap();
+    set_trailing(&a, "changed", CommentKind::Line).unwrap();
+    let b: CstNode = obj.get("b").unwrap().into();
+    set_trailing(&b, "last", CommentKind::Block).unwrap();
+    assert_eq!(
+      root.to_string(),
+      "{\n  // added by tool X\n  \"a\": 1, // changed\n  \"b\": [1, 2] /* last */\n}"
+    );
+    assert_eq!(leading(&a)[0].raw_value(), "// added by tool X");
+    assert_eq!(trailing(&a)[0].raw_value(), "// changed");
+  }
+
+  #[test]
+  fn adds_comments_to_array_elements() {
+    let root =
+      parse_root("{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": [1, 2]\n}");
+    let obj = root.object_value().unwrap();
+    let elements = obj.array_value("a").unwrap().elements();
+    set_leading(&elements[1], "second", CommentKind::Line).unwrap();
+    set_trailing(&elements[0], "first", CommentKind::Line).unwrap();
+    let elements = obj.array_value("b").unwrap().elements();
+    set_leading(&elements[1], "second", CommentKind::Block).unwrap();
+    set_trailing(&elements[0], "first", CommentKind::Block).unwrap();
+    assert_eq!(
+      root.to_string(),
+      concat!(
+        "{\n  \"a\": [\n    1, // first\n    // second\n    2\n  ],\n",
+        "  \"b\": [1 /* first */, /* second */ 2]\n}",
+      )
+    );
+    assert_eq!(trailing(&elements[0])[0].raw_value(), "/* first */");
+    assert_eq!(leading(&elements[1])[0].raw_value(), "/* second */");
+
+    // line comments would comment out the rest of the line
+    assert_eq!(
+      set_leading(&elements[0], "text", CommentKind::Line),
+      Err(CommentError::LineCommentNotAtEndOfLine)
+    );
+  }
+}
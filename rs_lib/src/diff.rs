@@ -0,0 +1,330 @@
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::patch::PatchOperation;
+use crate::patch::json_equal;
+use crate::pointer;
+
+/// Options for computing a diff.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiffOptions {
+  /// Emit `move` operations for array elements that changed position
+  /// instead of removing and adding them.
+  pub detect_moves: bool,
+}
+
+/// Computes the JSON Patch (RFC 6902) operations that transform `a`
+/// into `b`.
+pub fn diff(
+  a: &Value,
+  b: &Value,
+  options: &DiffOptions,
+) -> Vec<PatchOperation> {
+  let mut operations = Vec::new();
+  diff_values(a, b, &mut Vec::new(), options, &mut operations);
+  operations
+}
+
+fn diff_values(
+  a: &Value,
+  b: &Value,
+  path: &mut Vec<String>,
+  options: &DiffOptions,
+  operations: &mut Vec<PatchOperation>,
+) {
+  if json_equal(a, b) {
+    return;
+  }
+  match (a, b) {
+    (Value::Object(a), Value::Object(b)) => {
+      diff_objects(a, b, path, options, operations)
+    }
+    (Value::Array(a), Value::Array(b)) => {
+      diff_arrays(a, b, path, options, operations)
+    }
+    _ => operations.push(PatchOperation::Replace {
+      path: pointer::format(path),
+      value: b.clone(),
+    }),
+  }
+}
+
+fn diff_objects(
+  a: &Map<String, Value>,
+  b: &Map<String, Value>,
+  path: &mut Vec<String>,
+  options: &DiffOptions,
+  operations: &mut Vec<PatchOperation>,
+) {
+  for (name, a_value) in a {
+    path.push(name.clone());
+    match b.get(name) {
+      Some(b_value) => diff_values(a_value, b_value, path, options, operations),
+      None => operations.push(PatchOperation::Remove {
+        path: pointer::format(path),
+      }),
+    }
+    path.pop();
+  }
+  for (name, b_value) in b {
+    if !a.contains_key(name) {
+      path.push(name.clone());
+      operations.push(PatchOperation::Add {
+        path: pointer::format(path),
+        value: b_value.clone(),
+      });
+      path.pop();
+    }
+  }
+}
+
+enum Edit {
+  Keep(usize, usize),
+  Delete(usize),
+  Insert(usize),
+}
+
+fn diff_arrays(
+  a: &[Value],
+  b: &[Value],
+  path: &mut Vec<String>,
+  options: &DiffOptions,
+  operations: &mut Vec<PatchOperation>,
+) {
+  let edits = edit_script(a, b);
+
+  // map each element of `b` to the element of `a` it comes from, if any
+  let mut sources = vec![None; b.len()];
+  let mut is_moved = vec![false; a.len()];
+  for edit in &edits {
+    if let Edit::Keep(i, j) = edit {
+      sources[*j] = Some(*i);
+    }
+  }
+  if options.detect_moves {
+    for edit in &edits {
+      let Edit::Delete(i) = edit else {
+        continue;
+      };
+      let found = edits.iter().find_map(|edit| match edit {
+        Edit::Insert(j)
+          if sources[*j].is_none() && json_equal(&a[*i], &b[*j]) =>
+        {
+          Some(*j)
+        }
+        _ => None,
+      });
+      if let Some(j) = found {
+        sources[j] = Some(*i);
+        is_moved[*i] = true;
+      }
+    }
+  }
+  // pair up the remaining deletes and inserts between the same kept
+  // elements so they become replacements
+  for run in edits.split(|edit| matches!(edit, Edit::Keep(..))) {
+    let deletes = run.iter().filter_map(|edit| match edit {
+      Edit::Delete(i) if !is_moved[*i] => Some(*i),
+      _ => None,
+    });
+    let inserts = run.iter().filter_map(|edit| match edit {
+      Edit::Insert(j) if sources[*j].is_none() => Some(*j),
+      _ => None,
+    });
+    for (i, j) in deletes.zip(inserts.collect::<Vec<_>>()) {
+      sources[j] = Some(i);
+    }
+  }
+
+  let mut is_kept = vec![false; a.len()];
+  for i in sources.iter().flatten() {
+    is_kept[*i] = true;
+  }
+  let mut current = (0..a.len()).collect::<Vec<_>>();
+
+  // remove from the end so the indexes of earlier elements stay valid
+  for i in (0..a.len()).rev() {
+    if !is_kept[i] {
+      path.push(i.to_string());
+      operations.push(PatchOperation::Remove {
+        path: pointer::format(path),
+      });
+      path.pop();
+      current.remove(i);
+    }
+  }
+
+  // move elements after the element that precedes them in `b`
+  let order = sources.iter().flatten().copied().collect::<Vec<_>>();
+  for (order_index, i) in order.iter().enumerate() {
+    if !is_moved[*i] {
+      continue;
+    }
+    let from = current.iter().position(|c| c == i).unwrap();
+    current.remove(from);
+    let to = match order_index {
+      0 => 0,
+      _ => {
+        let previous = order[order_index - 1];
+        current.iter().position(|c| *c == previous).unwrap() + 1
+      }
+    };
+    current.insert(to, *i);
+    if from != to {
+      operations.push(PatchOperation::Move {
+        from: format_index(path, from),
+        path: format_index(path, to),
+      });
+    }
+  }
+
+  // the remaining elements are now in the same order as in `b`
+  for (index, (i, j)) in sources
+    .iter()
+    .enumerate()
+    .filter_map(|(j, source)| source.map(|i| (i, j)))
+    .enumerate()
+  {
+    path.push(index.to_string());
+    diff_values(&a[i], &b[j], path, options, operations);
+    path.pop();
+  }
+
+  for (j, source) in sources.iter().enumerate() {
+    if source.is_none() {
+      operations.push(PatchOperation::Add {
+        path: format_index(path, j),
+        value: b[j].clone(),
+      });
+    }
+  }
+}
+
+fn format_index(path: &[String], index: usize) -> String {
+  format!("{}/{}", pointer::format(path), index)
+}
+
+/// Computes the edits between the arrays based on their longest common
+/// subsequence.
+fn edit_script(a: &[Value], b: &[Value]) -> Vec<Edit> {
+  let prefix_len = a
+    .iter()
+    .zip(b)
+    .take_while(|(a, b)| json_equal(a, b))
+    .count();
+  let suffix_len = a[prefix_len..]
+    .iter()
+    .rev()
+    .zip(b[prefix_len..].iter().rev())
+    .take_while(|(a, b)| json_equal(a, b))
+    .count();
+  let a_middle = &a[prefix_len..a.len() - suffix_len];
+  let b_middle = &b[prefix_len..b.len() - suffix_len];
+
+  // lengths[i][j] is the length of the longest common subsequence of
+  // a_middle[i..] and b_middle[j..]
+  let mut lengths = vec![vec![0usize; b_middle.len() + 1]; a_middle.len() + 1];
+  for i in (0..a_middle.len()).rev() {
+    for j in (0..b_middle.len()).rev() {
+      lengths[i][j] = if json_equal(&a_middle[i], &b_middle[j]) {
+        lengths[i + 1][j + 1] + 1
+      } else {
+        lengths[i + 1][j].max(lengths[i][j + 1])
+      };
+    }
+  }
+
+  let mut edits = (0..prefix_len)
+    .map(|i| Edit::Keep(i, i))
+    .collect::<Vec<_>>();
+  let (mut i, mut j) = (0, 0);
+  while i < a_middle.len() || j < b_middle.len() {
+    if i < a_middle.len()
+      && j < b_middle.len()
+      && json_equal(&a_middle[i], &b_middle[j])
+    {
+      edits.push(Edit::Keep(prefix_len + i, prefix_len + j));
+      i += 1;
+      j += 1;
+    } else if j == b_middle.len()
+      || (i < a_middle.len() && lengths[i + 1][j] >= lengths[i][j + 1])
+    {
+      edits.push(Edit::Delete(prefix_len + i));
+      i += 1;
+    } else {
+      edits.push(Edit::Insert(prefix_len + j));
+      j += 1;
+    }
+  }
+  let a_suffix_start = a.len() - suffix_len;
+  let b_suffix_start = b.len() - suffix_len;
+  edits.extend(
+    (0..suffix_len).map(|k| Edit::Keep(a_suffix_start + k, b_suffix_start + k)),
+  );
+  edits
+}
+
+#[cfg(test)]
+mod test {
+  use serde_json::json;
+
+  use super::*;
+
+  fn operations(a: Value, b: Value, detect_moves: bool) -> Vec<Value> {
+    diff(&a, &b, &DiffOptions { detect_moves })
+      .iter()
+      .map(|operation| operation.to_value())
+      .collect()
+  }
+
+  #[test]
+  fn keeps_longest_common_subsequence() {
+    let edits = edit_script(
+      &[json!(1), json!(2), json!(3), json!(4), json!(5)],
+      &[json!(1), json!(3), json!(6), json!(4), json!(5)],
+    );
+    let kept = edits
+      .iter()
+      .filter_map(|edit| match edit {
+        Edit::Keep(i, j) => Some((*i, *j)),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(kept, [(0, 0), (2, 1), (3, 3), (4, 4)]);
+
+    assert_eq!(
+      operations(json!([1, 2, 3, 4, 5]), json!([1, 3, 6, 4, 5]), false),
+      [
+        json!({ "op": "remove", "path": "/1" }),
+        json!({ "op": "add", "path": "/2", "value": 6 }),
+      ]
+    );
+    assert_eq!(
+      operations(json!({ "a": [1, 2] }), json!({ "a": [1, 9] }), false),
+      [json!({ "op": "replace", "path": "/a/1", "value": 9 })]
+    );
+  }
+
+  #[test]
+  fn detects_moves() {
+    assert_eq!(
+      operations(json!([1, 2, 3]), json!([3, 1, 2]), false),
+      [
+        json!({ "op": "remove", "path": "/2" }),
+        json!({ "op": "add", "path": "/0", "value": 3 }),
+      ]
+    );
+    assert_eq!(
+      operations(json!([1, 2, 3]), json!([3, 1, 2]), true),
+      [json!({ "op": "move", "from": "/2", "path": "/0" })]
+    );
+    assert_eq!(
+      operations(
+        json!([{ "a": 1 }, "b", "c"]),
+        json!(["b", "c", { "a": 1 }]),
+        true
+      ),
+      [json!({ "op": "move", "from": "/0", "path": "/2" })]
+    );
+  }
+}
@@ -0,0 +1,323 @@
+use std::cell::RefCell;
+
+use jsonc_parser::ParseStringErrorKind;
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstLeafNode;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::errors::ParseErrorKind;
+
+use wasm_bindgen::prelude::*;
+
+use crate::pointer;
+use crate::position;
+use crate::position::LineColumn;
+use crate::position::TextRange;
+use crate::wasm::to_js_object;
+
+/// Error thrown when parsing JSONC text fails.
+/// Contains the location of the error so it can be reported to users.
+///
+/// It's thrown as the `ParseError` class defined in mod.ts.
+pub struct ParseError {
+  code: &'static str,
+  message: String,
+  range: TextRange,
+  utf16_range: TextRange,
+  start: LineColumn,
+  end: LineColumn,
+  code_frame: String,
+}
+
+impl ParseError {
+  pub(crate) fn new(
+    err: &jsonc_parser::errors::ParseError,
+    text: &str,
+  ) -> Self {
+    let range = TextRange {
+      start: err.range().start,
+      end: err.range().end,
+    };
+    ParseError {
+      code: parse_error_code(err.kind()),
+      message: position::parse_error_message(err, text),
+      range,
+      utf16_range: position::utf16_range(text, range),
+      start: position::line_column(text, range.start),
+      end: position::line_column(text, range.end),
+      code_frame: position::code_frame(text, range),
+    }
+  }
+}
+
+impl From<ParseError> for JsValue {
+  fn from(err: ParseError) -> Self {
+    let error =
+      new_error("ParseError", &err.message, |classes| &classes.parse_error);
+    set_field(&error, "code", err.code);
+    set_field(&error, "range", to_js_object::<_, JsValue>(&err.range));
+    set_field(
+      &error,
+      "utf16Range",
+      to_js_object::<_, JsValue>(&err.utf16_range),
+    );
+    set_field(&error, "start", to_js_object::<_, JsValue>(&err.start));
+    set_field(&error, "end", to_js_object::<_, JsValue>(&err.end));
+    set_field(&error, "codeFrame", err.code_frame);
+    error
+  }
+}
+
+/// Kind of failure of a `JsoncMorphError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ErrorCode {
+  /// A root or property has no value.
+  ExpectedValue,
+  /// A property has no name.
+  ExpectedName,
+  /// A node has no parent.
+  ExpectedParent,
+  ExpectedObject,
+  ExpectedArray,
+  /// A node is a different kind than the one expected, other than an
+  /// object or array.
+  WrongValueKind,
+  PropertyNotFound,
+  /// A string literal can't be decoded.
+  InvalidString,
+  /// A node was removed from its document.
+  DetachedNode,
+}
+
+impl ErrorCode {
+  fn as_str(self) -> &'static str {
+    match self {
+      ErrorCode::ExpectedValue => "EXPECTED_VALUE",
+      ErrorCode::ExpectedName => "EXPECTED_NAME",
+      ErrorCode::ExpectedParent => "EXPECTED_PARENT",
+      ErrorCode::ExpectedObject => "EXPECTED_OBJECT",
+      ErrorCode::ExpectedArray => "EXPECTED_ARRAY",
+      ErrorCode::WrongValueKind => "WRONG_VALUE_KIND",
+      ErrorCode::PropertyNotFound => "PROPERTY_NOT_FOUND",
+      ErrorCode::InvalidString => "INVALID_STRING",
+      ErrorCode::DetachedNode => "DETACHED_NODE",
+    }
+  }
+}
+
+/// Error thrown when a node isn't what an operation expects, such as by the
+/// `*OrThrow` methods.
+/// Contains the code of the failure and the node it occurred at so it can be
+/// handled without matching the message.
+///
+/// It's thrown as the `JsoncMorphError` class defined in mod.ts.
+pub struct JsoncMorphError {
+  code: ErrorCode,
+  message: String,
+  key: Option<String>,
+  expected: Option<&'static str>,
+  actual: Option<&'static str>,
+  pointer: Option<String>,
+  range: Option<TextRange>,
+  utf16_range: Option<TextRange>,
+}
+
+impl JsoncMorphError {
+  pub(crate) fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+    JsoncMorphError {
+      code,
+      message: message.into(),
+      key: None,
+      expected: None,
+      actual: None,
+      pointer: None,
+      range: None,
+      utf16_range: None,
+    }
+  }
+
+  /// Sets the node the error occurred at. The pointer and range are only
+  /// known when the node is attached to a document.
+  pub(crate) fn at(mut self, node: &CstNode) -> Self {
+    if node.root_node().is_some() {
+      self.pointer = node
+        .root_node()
+        .and_then(|root| pointer::pointer_of(&root, node));
+      self.range = Some(position::node_range(node));
+      self.utf16_range = Some(position::node_utf16_range(node));
+    }
+    self
+  }
+
+  /// Sets the property name that was looked up.
+  pub(crate) fn key(mut self, key: &str) -> Self {
+    self.key = Some(key.to_string());
+    self
+  }
+
+  /// Sets the expected kind of node and the kind that was found.
+  pub(crate) fn kinds(
+    mut self,
+    expected: &'static str,
+    actual: Option<&CstNode>,
+  ) -> Self {
+    self.expected = Some(expected);
+    self.actual = actual.map(kind_name);
+    self
+  }
+}
+
+impl From<JsoncMorphError> for JsValue {
+  fn from(err: JsoncMorphError) -> Self {
+    let error = new_error("JsoncMorphError", &err.message, |classes| {
+      &classes.jsonc_morph_error
+    });
+    set_field(&error, "code", err.code.as_str());
+    if let Some(key) = err.key {
+      set_field(&error, "key", key);
+    }
+    if let Some(expected) = err.expected {
+      set_field(&error, "expected", expected);
+    }
+    if let Some(actual) = err.actual {
+      set_field(&error, "actual", actual);
+    }
+    if let Some(pointer) = err.pointer {
+      set_field(&error, "pointer", pointer);
+    }
+    if let Some(range) = err.range {
+      set_field(&error, "range", to_js_object::<_, JsValue>(&range));
+    }
+    if let Some(utf16_range) = err.utf16_range {
+      set_field(
+        &error,
+        "utf16Range",
+        to_js_object::<_, JsValue>(&utf16_range),
+      );
+    }
+    error
+  }
+}
+
+struct ErrorClasses {
+  parse_error: js_sys::Function,
+  jsonc_morph_error: js_sys::Function,
+}
+
+thread_local! {
+  static ERROR_CLASSES: RefCell<Option<ErrorClasses>> =
+    const { RefCell::new(None) };
+}
+
+/// Sets the `Error` subclasses that `ParseError` and `JsoncMorphError` are
+/// constructed with. This is called by mod.ts when it's loaded, and plain
+/// `Error` objects are thrown until it is.
+#[wasm_bindgen(js_name = setErrorClasses)]
+pub fn set_error_classes(
+  parse_error: js_sys::Function,
+  jsonc_morph_error: js_sys::Function,
+) {
+  ERROR_CLASSES.with_borrow_mut(|classes| {
+    *classes = Some(ErrorClasses {
+      parse_error,
+      jsonc_morph_error,
+    });
+  });
+}
+
+/// Creates an error with the registered class so it has a stack and is an
+/// instance of both the class and `Error`.
+fn new_error(
+  name: &str,
+  message: &str,
+  class: impl FnOnce(&ErrorClasses) -> &js_sys::Function,
+) -> JsValue {
+  let error = ERROR_CLASSES.with_borrow(|classes| {
+    let class = class(classes.as_ref()?);
+    let args = js_sys::Array::of1(&JsValue::from_str(message));
+    js_sys::Reflect::construct(class, &args).ok()
+  });
+  error.unwrap_or_else(|| {
+    let error = js_sys::Error::new(message);
+    error.set_name(name);
+    error.into()
+  })
+}
+
+fn set_field(error: &JsValue, name: &str, value: impl Into<JsValue>) {
+  // setting a property on a newly created error can't fail
+  let _ = js_sys::Reflect::set(error, &JsValue::from_str(name), &value.into());
+}
+
+/// Gets the name of the kind of node used by `JsoncMorphError`.
+pub(crate) fn kind_name(node: &CstNode) -> &'static str {
+  match node {
+    CstNode::Container(container) => match container {
+      CstContainerNode::Root(_) => "root",
+      CstContainerNode::Object(_) => "object",
+      CstContainerNode::Array(_) => "array",
+      CstContainerNode::ObjectProp(_) => "property",
+    },
+    CstNode::Leaf(leaf) => match leaf {
+      CstLeafNode::StringLit(_) => "string",
+      CstLeafNode::NumberLit(_) => "number",
+      CstLeafNode::BooleanLit(_) => "boolean",
+      CstLeafNode::NullKeyword(_) => "null",
+      CstLeafNode::WordLit(_) => "word",
+      CstLeafNode::Token(_) => "token",
+      CstLeafNode::Whitespace(_) => "whitespace",
+      CstLeafNode::Newline(_) => "newline",
+      CstLeafNode::Comment(_) => "comment",
+    },
+  }
+}
+
+fn parse_error_code(kind: &ParseErrorKind) -> &'static str {
+  use ParseErrorKind::*;
+  match kind {
+    CommentsNotAllowed => "COMMENTS_NOT_ALLOWED",
+    ExpectedColonAfterObjectKey => "EXPECTED_COLON_AFTER_OBJECT_KEY",
+    ExpectedObjectValue => "EXPECTED_OBJECT_VALUE",
+    ExpectedDigit => "EXPECTED_DIGIT",
+    ExpectedDigitFollowingNegativeSign => {
+      "EXPECTED_DIGIT_FOLLOWING_NEGATIVE_SIGN"
+    }
+    ExpectedPlusMinusOrDigitInNumberLiteral => {
+      "EXPECTED_PLUS_MINUS_OR_DIGIT_IN_NUMBER_LITERAL"
+    }
+    ExpectedStringObjectProperty => "EXPECTED_STRING_OBJECT_PROPERTY",
+    HexadecimalNumbersNotAllowed => "HEXADECIMAL_NUMBERS_NOT_ALLOWED",
+    ExpectedComma => "EXPECTED_COMMA",
+    MultipleRootJsonValues => "MULTIPLE_ROOT_JSON_VALUES",
+    SingleQuotedStringsNotAllowed => "SINGLE_QUOTED_STRINGS_NOT_ALLOWED",
+    String(kind) => match kind {
+      ParseStringErrorKind::InvalidEscapeInSingleQuoteString => {
+        "INVALID_ESCAPE_IN_SINGLE_QUOTE_STRING"
+      }
+      ParseStringErrorKind::InvalidEscapeInDoubleQuoteString => {
+        "INVALID_ESCAPE_IN_DOUBLE_QUOTE_STRING"
+      }
+      ParseStringErrorKind::ExpectedFourHexDigits => "EXPECTED_FOUR_HEX_DIGITS",
+      ParseStringErrorKind::InvalidUnicodeEscapeSequence(_) => {
+        "INVALID_UNICODE_ESCAPE_SEQUENCE"
+      }
+      ParseStringErrorKind::InvalidEscape => "INVALID_ESCAPE",
+      ParseStringErrorKind::UnterminatedStringLiteral => {
+        "UNTERMINATED_STRING_LITERAL"
+      }
+    },
+    TrailingCommasNotAllowed => "TRAILING_COMMAS_NOT_ALLOWED",
+    UnaryPlusNumbersNotAllowed => "UNARY_PLUS_NUMBERS_NOT_ALLOWED",
+    UnexpectedCloseBrace => "UNEXPECTED_CLOSE_BRACE",
+    UnexpectedCloseBracket => "UNEXPECTED_CLOSE_BRACKET",
+    UnexpectedColon => "UNEXPECTED_COLON",
+    UnexpectedComma => "UNEXPECTED_COMMA",
+    UnexpectedToken => "UNEXPECTED_TOKEN",
+    UnexpectedTokenInObject => "UNEXPECTED_TOKEN_IN_OBJECT",
+    UnexpectedWord => "UNEXPECTED_WORD",
+    UnterminatedArray => "UNTERMINATED_ARRAY",
+    UnterminatedCommentBlock => "UNTERMINATED_COMMENT_BLOCK",
+    UnterminatedObject => "UNTERMINATED_OBJECT",
+    NestingDepthExceeded => "NESTING_DEPTH_EXCEEDED",
+    Custom(_) => "CUSTOM",
+  }
+}
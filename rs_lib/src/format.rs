@@ -0,0 +1,651 @@
+use jsonc_parser::cst::CstComment;
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstLeafNode;
+use jsonc_parser::cst::CstNewlineKind;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstRootNode;
+use jsonc_parser::cst::CstStringLit;
+use jsonc_parser::cst::ObjectPropName;
+use jsonc_parser::cst::TrailingCommaMode;
+
+use crate::comments;
+use crate::prop_name;
+use crate::trivia;
+
+/// Quote style of strings and quoted property names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteStyle {
+  /// Keep the current quotes.
+  #[default]
+  Preserve,
+  Double,
+  Single,
+}
+
+/// Options for formatting a document.
+#[derive(Debug, Clone)]
+pub struct FormatOptions {
+  /// Text of a single level of indentation.
+  pub indent_text: String,
+  /// Number of columns a tab in the indentation counts as when measuring
+  /// the line width.
+  pub tab_width: usize,
+  pub newline: &'static str,
+  /// Maximum line width when deciding whether an object or array fits on a
+  /// single line. When `None`, there is no maximum.
+  pub line_width: Option<usize>,
+  /// Whether to put multi-line objects and arrays on a single line when they
+  /// fit. Otherwise, they stay on multiple lines like dprint does when the
+  /// first element is on a different line than the open brace or bracket.
+  pub prefer_single_line: bool,
+  /// Maximum number of consecutive blank lines to keep.
+  pub max_blank_lines: usize,
+  pub quote_style: QuoteStyle,
+  /// Whether multi-line objects and arrays have a trailing comma. When
+  /// `None`, each keeps whether it has one.
+  pub trailing_commas: Option<bool>,
+  /// Whether the text ends with a newline.
+  pub final_newline: bool,
+}
+
+impl FormatOptions {
+  /// Gets the options that keep the document's current indentation,
+  /// newlines and blank lines.
+  pub fn for_root(root: &CstRootNode) -> Self {
+    Self {
+      indent_text: root
+        .single_indent_text()
+        .unwrap_or_else(|| "  ".to_string()),
+      tab_width: 2,
+      newline: match root.newline_kind() {
+        CstNewlineKind::LineFeed => "\n",
+        CstNewlineKind::CarriageReturnLineFeed => "\r\n",
+      },
+      line_width: None,
+      prefer_single_line: false,
+      max_blank_lines: usize::MAX,
+      quote_style: QuoteStyle::Preserve,
+      trailing_commas: None,
+      final_newline: root.children().last().is_some_and(|c| c.is_newline()),
+    }
+  }
+
+  /// Gets the defaults of a full reformat, which match dprint-plugin-json's.
+  pub fn dprint(root: &CstRootNode) -> Self {
+    Self {
+      indent_text: "  ".to_string(),
+      line_width: Some(120),
+      max_blank_lines: 1,
+      final_newline: true,
+      ..Self::for_root(root)
+    }
+  }
+}
+
+/// Formats the document, keeping its comments.
+pub fn format(root: &CstRootNode, options: &FormatOptions) -> String {
+  let formatter = Formatter { options };
+  let entries = entries(&root.children());
+  let lines = formatter.lines(&entries, 0, None);
+  let mut text = formatter.join(&lines, 0);
+  if options.final_newline && !text.is_empty() {
+    text.push_str(options.newline);
+  }
+  text
+}
+
+/// Applies the indentation, newline, quote style and trailing comma options
+/// to the document without changing where its lines break. The line width,
+/// single line preference, blank line and final newline options are ignored.
+pub fn restyle(root: &CstRootNode, options: &FormatOptions) {
+  trivia::set_indent_text(root, &options.indent_text);
+  let newline_kind = match options.newline {
+    "\r\n" => CstNewlineKind::CarriageReturnLineFeed,
+    _ => CstNewlineKind::LineFeed,
+  };
+  trivia::set_newline_kind(root, newline_kind);
+  if options.quote_style != QuoteStyle::Preserve {
+    for leaf in trivia::leaves(&root.clone().into()) {
+      if let CstLeafNode::StringLit(lit) = leaf {
+        let text = quoted(&lit, options.quote_style);
+        lit.set_raw_value(text);
+      }
+    }
+  }
+  if let Some(trailing_commas) = options.trailing_commas {
+    root.set_trailing_commas(if trailing_commas {
+      TrailingCommaMode::IfMultiline
+    } else {
+      TrailingCommaMode::Never
+    });
+  }
+}
+
+/// Gets the text of the string with the quote style, keeping the current
+/// quotes when the string can't be decoded.
+fn quoted(lit: &CstStringLit, quote_style: QuoteStyle) -> String {
+  let raw = lit.raw_value();
+  let is_single_quoted = raw.starts_with('\'');
+  let quote: fn(&str) -> String = match quote_style {
+    QuoteStyle::Double if is_single_quoted => prop_name::double_quoted,
+    QuoteStyle::Single if !is_single_quoted => prop_name::single_quoted,
+    _ => return raw,
+  };
+  match lit.decoded_value() {
+    Ok(value) => quote(&value),
+    Err(_) => raw,
+  }
+}
+
+/// An element of an object, array or the root along with its comments.
+struct Entry {
+  leading: Vec<LeadingComment>,
+  /// The property or value, which is `None` for the comments after the
+  /// last element.
+  element: Option<CstNode>,
+  /// Whether the element starts a line.
+  own_line: bool,
+  blank_lines_before: usize,
+  /// Comments after the element on the same line.
+  trailing: Vec<CstComment>,
+  has_comma: bool,
+}
+
+struct LeadingComment {
+  comment: CstComment,
+  /// Whether the comment starts a line.
+  own_line: bool,
+  blank_lines_before: usize,
+}
+
+/// A line of formatted text, without its indentation.
+struct Line {
+  text: String,
+  blank_lines_before: usize,
+}
+
+/// Groups the children of an object, array or the root into entries.
+fn entries(children: &[CstNode]) -> Vec<Entry> {
+  let mut entries: Vec<Entry> = Vec::new();
+  let mut leading = Vec::new();
+  // comments after a comma on the element's line, which belong to the next
+  // element when it's on the same line
+  let mut after_comma = Vec::new();
+  let mut newlines: usize = 0;
+  let mut on_element_line = false;
+  for child in children {
+    if child.is_newline() {
+      if let Some(entry) = entries.last_mut() {
+        entry.trailing.append(&mut after_comma);
+      }
+      newlines += 1;
+      on_element_line = false;
+    } else if child.is_whitespace() {
+      continue;
+    } else if let Some(comment) = child.as_comment() {
+      match entries.last_mut() {
+        Some(entry) if on_element_line && !entry.has_comma => {
+          entry.trailing.push(comment)
+        }
+        _ if on_element_line => after_comma.push(comment),
+        _ => {
+          leading.push(LeadingComment {
+            comment,
+            own_line: newlines > 0,
+            blank_lines_before: newlines.saturating_sub(1),
+          });
+          newlines = 0;
+        }
+      }
+    } else if child.is_comma() {
+      if let Some(entry) = entries.last_mut() {
+        entry.has_comma = true;
+      }
+    } else if child.as_token().is_none() {
+      leading.extend(after_comma.drain(..).map(|comment| LeadingComment {
+        comment,
+        own_line: false,
+        blank_lines_before: 0,
+      }));
+      entries.push(Entry {
+        leading: std::mem::take(&mut leading),
+        element: Some(child.clone()),
+        own_line: newlines > 0,
+        blank_lines_before: newlines.saturating_sub(1),
+        trailing: Vec::new(),
+        has_comma: false,
+      });
+      newlines = 0;
+      on_element_line = true;
+    }
+  }
+  if let Some(entry) = entries.last_mut() {
+    entry.trailing.append(&mut after_comma);
+  }
+  if !leading.is_empty() {
+    entries.push(Entry {
+      leading,
+      element: None,
+      own_line: true,
+      blank_lines_before: 0,
+      trailing: Vec::new(),
+      has_comma: false,
+    });
+  }
+  entries
+}
+
+struct Formatter<'a> {
+  options: &'a FormatOptions,
+}
+
+impl Formatter<'_> {
+  /// Formats the entries as lines at the indentation level, where
+  /// `trailing_comma` is whether the last element has a comma or `None` when
+  /// the entries aren't separated by commas.
+  fn lines(
+    &self,
+    entries: &[Entry],
+    level: usize,
+    trailing_comma: Option<bool>,
+  ) -> Vec<Line> {
+    let indent_width = self.indent_width(level);
+    let last_element = entries.iter().rposition(|e| e.element.is_some());
+    let mut lines = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+      let mut line: Option<Line> = None;
+      for leading in &entry.leading {
+        let text = self.comment(&leading.comment, level);
+        match &mut line {
+          Some(line) if !leading.own_line => {
+            line.text.push(' ');
+            line.text.push_str(&text);
+          }
+          _ => {
+            lines.extend(line.take());
+            line = Some(Line {
+              text,
+              blank_lines_before: leading.blank_lines_before,
+            });
+          }
+        }
+        if leading.comment.is_line_comment() {
+          lines.extend(line.take());
+        }
+      }
+      if let Some(element) = &entry.element {
+        if entry.own_line {
+          lines.extend(line.take());
+        }
+        let mut line = match line.take() {
+          Some(mut line) => {
+            line.text.push(' ');
+            line
+          }
+          None => Line {
+            text: String::new(),
+            blank_lines_before: entry.blank_lines_before,
+          },
+        };
+        let column = indent_width + line.text.chars().count();
+        line.text.push_str(&self.element(element, level, column));
+        let has_comma = match trailing_comma {
+          None => false,
+          Some(_) if Some(index) != last_element => true,
+          Some(trailing_comma) => trailing_comma,
+        };
+        if has_comma {
+          line.text.push(',');
+        }
+        for comment in &entry.trailing {
+          line.text.push(' ');
+          line.text.push_str(&self.comment(comment, level));
+        }
+        lines.push(line);
+      } else {
+        lines.extend(line);
+      }
+    }
+    if let Some(first) = lines.first_mut() {
+      first.blank_lines_before = 0;
+    }
+    lines
+  }
+
+  /// Joins the lines, indenting them to the level.
+  fn join(&self, lines: &[Line], level: usize) -> String {
+    let indent = self.options.indent_text.repeat(level);
+    let mut text = String::new();
+    for (index, line) in lines.iter().enumerate() {
+      if index > 0 {
+        let blank_lines =
+          line.blank_lines_before.min(self.options.max_blank_lines);
+        for _ in 0..=blank_lines {
+          text.push_str(self.options.newline);
+        }
+      }
+      text.push_str(&indent);
+      text.push_str(&line.text);
+    }
+    text
+  }
+
+  /// Formats a property or value that starts at the column.
+  fn element(&self, node: &CstNode, level: usize, column: usize) -> String {
+    match node {
+      CstNode::Container(CstContainerNode::ObjectProp(prop)) => {
+        let mut text = match prop.name() {
+          Some(ObjectPropName::String(lit)) => {
+            quoted(&lit, self.options.quote_style)
+          }
+          Some(ObjectPropName::Word(word)) => word.to_string(),
+          None => String::new(),
+        };
+        text.push(':');
+        let mut level = level;
+        // where the value's line starts in `text` and the column there
+        let mut line_start = (0, column);
+        for comment in comments::inner(&prop.clone().into()) {
+          text.push(' ');
+          text.push_str(&self.comment(&comment, level));
+          if comment.is_line_comment() {
+            // the value continues on the next line
+            level += 1;
+            text.push_str(self.options.newline);
+            text.push_str(&self.options.indent_text.repeat(level));
+            line_start = (text.len(), self.indent_width(level));
+          }
+        }
+        if let Some(value) = prop.value() {
+          if line_start.0 != text.len() {
+            text.push(' ');
+          }
+          let column = line_start.1 + text[line_start.0..].chars().count();
+          text.push_str(&self.element(&value, level, column));
+        }
+        text
+      }
+      CstNode::Container(CstContainerNode::Object(_))
+      | CstNode::Container(CstContainerNode::Array(_)) => {
+        self.container(node, level, column)
+      }
+      _ => match node.as_string_lit() {
+        Some(lit) => quoted(&lit, self.options.quote_style),
+        None => node.to_string(),
+      },
+    }
+  }
+
+  /// Formats an object or array that starts at the column.
+  fn container(&self, node: &CstNode, level: usize, column: usize) -> String {
+    let is_object = node.as_object().is_some();
+    let (open, close) = if is_object { ('{', '}') } else { ('[', ']') };
+    let children = node.children();
+    let entries = entries(&children);
+    if entries.is_empty() {
+      return format!("{}{}", open, close);
+    }
+
+    let has_line_comment = entries.iter().any(|entry| {
+      entry.leading.iter().any(|c| c.comment.is_line_comment())
+        || entry.trailing.iter().any(|c| c.is_line_comment())
+        || entry.element.as_ref().is_some_and(|element| {
+          element.as_object_prop().is_some()
+            && comments::inner(element).iter().any(|c| c.is_line_comment())
+        })
+    });
+    // like dprint, a container is multi-line when its first element or
+    // comment is on a different line than the open brace or bracket
+    let is_multiline = children
+      .iter()
+      .skip(1)
+      .find(|c| !c.is_whitespace())
+      .is_some_and(|c| c.is_newline());
+    if !has_line_comment && (!is_multiline || self.options.prefer_single_line) {
+      let text = self.single_line(&entries, is_object, level, column);
+      let fits = self.options.line_width.is_none_or(|width| {
+        !text.contains('\n') && column + text.chars().count() <= width
+      });
+      if fits {
+        return text;
+      }
+    }
+
+    let trailing_comma = self.options.trailing_commas.unwrap_or_else(|| {
+      entries
+        .iter()
+        .rev()
+        .find(|e| e.element.is_some())
+        .is_some_and(|e| e.has_comma)
+    });
+    let lines = self.lines(&entries, level + 1, Some(trailing_comma));
+    format!(
+      "{}{}{}{}{}{}",
+      open,
+      self.options.newline,
+      self.join(&lines, level + 1),
+      self.options.newline,
+      self.options.indent_text.repeat(level),
+      close
+    )
+  }
+
+  /// Formats the entries of an object or array on a single line.
+  fn single_line(
+    &self,
+    entries: &[Entry],
+    is_object: bool,
+    level: usize,
+    column: usize,
+  ) -> String {
+    let padding = if is_object { " " } else { "" };
+    let mut text = String::new();
+    text.push(if is_object { '{' } else { '[' });
+    text.push_str(padding);
+    let mut items = Vec::new();
+    for entry in entries {
+      let mut item = Vec::new();
+      for leading in &entry.leading {
+        item.push(self.comment(&leading.comment, level));
+      }
+      if let Some(element) = &entry.element {
+        let column = column
+          + text.chars().count()
+          + items
+            .iter()
+            .map(|i: &String| i.chars().count() + 2)
+            .sum::<usize>()
+          + item.iter().map(|i| i.chars().count() + 1).sum::<usize>();
+        item.push(self.element(element, level, column));
+      }
+      for comment in &entry.trailing {
+        item.push(self.comment(comment, level));
+      }
+      items.push(item.join(" "));
+    }
+    text.push_str(&items.join(", "));
+    text.push_str(padding);
+    text.push(if is_object { '}' } else { ']' });
+    text
+  }
+
+  /// Formats a comment on a line at the level. The lines of a multi-line
+  /// block comment are re-indented so they stay aligned with its first line.
+  fn comment(&self, comment: &CstComment, level: usize) -> String {
+    let raw = comment.raw_value();
+    if !raw.contains('\n') {
+      return raw;
+    }
+    let old_indent = trivia::line_indent(&comment.clone().into());
+    let new_indent = self.options.indent_text.repeat(level);
+    let mut lines = raw.lines();
+    let mut text = lines.next().unwrap_or_default().to_string();
+    for line in lines {
+      text.push_str(self.options.newline);
+      match line.strip_prefix(old_indent.as_str()) {
+        Some(rest) => {
+          text.push_str(&new_indent);
+          text.push_str(rest);
+        }
+        None if line.trim().is_empty() => {}
+        None => {
+          text.push_str(&new_indent);
+          text.push_str(line.trim_start());
+        }
+      }
+    }
+    text
+  }
+
+  fn indent_width(&self, level: usize) -> usize {
+    let width = self
+      .options
+      .indent_text
+      .chars()
+      .map(|c| if c == '\t' { self.options.tab_width } else { 1 })
+      .sum::<usize>();
+    width * level
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+
+  use super::*;
+
+  fn format_text(
+    text: &str,
+    options: impl FnOnce(&mut FormatOptions),
+  ) -> String {
+    let root = CstRootNode::parse(text, &ParseOptions::default()).unwrap();
+    let mut format_options = FormatOptions::dprint(&root);
+    options(&mut format_options);
+    format(&root, &format_options)
+  }
+
+  #[test]
+  fn keeps_comments() {
+    assert_eq!(
+      format_text(
+        "// header\n{  // after brace\n  /* leading */ \"a\":1, // a\n  \"b\" : [1,2] /* b */\n  // last\n}",
+        |_| {}
+      ),
+      "// header\n{\n  // after brace\n  /* leading */ \"a\": 1, // a\n  \"b\": [1, 2] /* b */\n  // last\n}\n"
+    );
+  }
+
+  #[test]
+  fn restyles_without_reflowing() {
+    let text = "{\n    \"a\": 1, // one\n\n\n    'b': [1,2,   3],\n    \"c\": {\"x\": 'y'},\n}\n";
+    let root = CstRootNode::parse(text, &ParseOptions::default()).unwrap();
+    let options = FormatOptions {
+      indent_text: "\t".to_string(),
+      newline: "\r\n",
+      quote_style: QuoteStyle::Double,
+      trailing_commas: Some(false),
+      ..FormatOptions::for_root(&root)
+    };
+    restyle(&root, &options);
+    assert_eq!(
+      root.to_string(),
+      "{\r\n\t\"a\": 1, // one\r\n\r\n\r\n\t\"b\": [1,2,   3],\r\n\t\"c\": {\"x\": \"y\"}\r\n}\r\n"
+    );
+  }
+
+  #[test]
+  fn caps_blank_lines() {
+    let text = "{\n  \"a\": 1,\n\n\n\n  \"b\": 2\n}";
+    assert_eq!(
+      format_text(text, |_| {}),
+      "{\n  \"a\": 1,\n\n  \"b\": 2\n}\n"
+    );
+    assert_eq!(
+      format_text(text, |o| o.max_blank_lines = 0),
+      "{\n  \"a\": 1,\n  \"b\": 2\n}\n"
+    );
+  }
+
+  #[test]
+  fn uses_crlf_newlines() {
+    assert_eq!(
+      format_text("{\r\n  \"a\": [\r\n    1 // one\r\n  ]\r\n}", |_| {}),
+      "{\r\n  \"a\": [\r\n    1 // one\r\n  ]\r\n}\r\n"
+    );
+    assert_eq!(
+      format_text("{\n\"a\": 1\n}", |o| o.newline = "\r\n"),
+      "{\r\n  \"a\": 1\r\n}\r\n"
+    );
+  }
+
+  #[test]
+  fn indents_with_tabs() {
+    let tabs = |o: &mut FormatOptions| o.indent_text = "\t".to_string();
+    assert_eq!(
+      format_text("{\n\"a\": {\n\"b\": [\n1\n]\n}\n}", tabs),
+      "{\n\t\"a\": {\n\t\t\"b\": [\n\t\t\t1\n\t\t]\n\t}\n}\n"
+    );
+  }
+
+  #[test]
+  fn measures_tabs_with_the_tab_width() {
+    // `"a": [1, 2]` is 11 columns after the indentation
+    let text = "{\n\t\"a\": [\n\t\t1,\n\t\t2\n\t]\n}";
+    let options = |tab_width: usize| {
+      move |o: &mut FormatOptions| {
+        o.indent_text = "\t".to_string();
+        o.tab_width = tab_width;
+        o.prefer_single_line = true;
+        o.line_width = Some(14);
+      }
+    };
+    assert_eq!(format_text(text, options(2)), "{\n\t\"a\": [1, 2]\n}\n");
+    assert_eq!(format_text(text, options(4)), format!("{}\n", text));
+  }
+
+  #[test]
+  fn sets_trailing_commas() {
+    let text = "{\n  \"a\": [\n    1,\n  ],\n  \"b\": [1, 2],\n  \"c\": 3\n}";
+    assert_eq!(
+      format_text(text, |_| {}),
+      "{\n  \"a\": [\n    1,\n  ],\n  \"b\": [1, 2],\n  \"c\": 3\n}\n"
+    );
+    assert_eq!(
+      format_text(text, |o| o.trailing_commas = Some(true)),
+      "{\n  \"a\": [\n    1,\n  ],\n  \"b\": [1, 2],\n  \"c\": 3,\n}\n"
+    );
+    assert_eq!(
+      format_text(text, |o| o.trailing_commas = Some(false)),
+      "{\n  \"a\": [\n    1\n  ],\n  \"b\": [1, 2],\n  \"c\": 3\n}\n"
+    );
+  }
+
+  #[test]
+  fn collapses_containers_that_fit() {
+    let text = "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": [\n    1, // one\n    2\n  ]\n}";
+    assert_eq!(
+      format_text(text, |o| o.prefer_single_line = true),
+      "{\n  \"a\": [1, 2],\n  \"b\": [\n    1, // one\n    2\n  ]\n}\n"
+    );
+    assert_eq!(
+      format_text(text, |o| {
+        o.prefer_single_line = true;
+        o.line_width = Some(10);
+      }),
+      "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": [\n    1, // one\n    2\n  ]\n}\n"
+    );
+  }
+
+  #[test]
+  fn changes_quotes_of_unicode_names() {
+    let text = "{ \"é\": 'it\\'s', '日本': \"\\\"q\\\"\", ünquoted: 1 }";
+    assert_eq!(
+      format_text(text, |o| o.quote_style = QuoteStyle::Single),
+      "{ 'é': 'it\\'s', '日本': '\"q\"', ünquoted: 1 }\n"
+    );
+    assert_eq!(
+      format_text(text, |o| o.quote_style = QuoteStyle::Double),
+      "{ \"é\": \"it's\", \"日本\": \"\\\"q\\\"\", ünquoted: 1 }\n"
+    );
+  }
+}
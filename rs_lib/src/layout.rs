@@ -0,0 +1,248 @@
+use std::fmt;
+
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstWhitespace;
+
+/// Error that occurs when a container can't be put on a single line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SingleLineError {
+  /// The container has a line comment, which would comment out the rest of
+  /// the line.
+  LineComment,
+}
+
+impl fmt::Display for SingleLineError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SingleLineError::LineComment => write!(
+        f,
+        "Cannot put the container on a single line because it has a line \
+         comment, which would comment out the rest of the line"
+      ),
+    }
+  }
+}
+
+/// Gets if the object or array has a newline between its braces or
+/// brackets, excluding newlines within nested objects and arrays.
+pub fn is_multiline(container: &CstContainerNode) -> bool {
+  container.children().iter().any(|child| {
+    child.is_newline()
+      || child
+        .as_object_prop()
+        .is_some_and(|prop| prop.children().iter().any(|c| c.is_newline()))
+  })
+}
+
+/// Puts the object or array, along with the objects and arrays within it, on
+/// a single line by removing newlines and indentation.
+///
+/// Objects are padded with a space like `{ "a": 1 }` and arrays aren't like
+/// `[1, 2]`. Trailing commas are removed. Whitespace that's already on a
+/// single line is left as is. Fails without changing anything when there's
+/// a line comment.
+pub fn ensure_single_line(
+  container: &CstContainerNode,
+) -> Result<(), SingleLineError> {
+  let node: CstNode = container.clone().into();
+  if contains_line_comment(&node) {
+    return Err(SingleLineError::LineComment);
+  }
+  collapse(&node);
+  Ok(())
+}
+
+fn collapse(node: &CstNode) {
+  for child in node.children() {
+    if matches!(child, CstNode::Container(_)) {
+      collapse(&child);
+    }
+  }
+  if let Some(comma) = trailing_comma(node) {
+    comma.remove();
+  }
+
+  let is_object = node.as_object().is_some();
+  add_missing_holders(node, is_object);
+  let runs = trivia_runs(&node.children());
+  let mut changes = Vec::with_capacity(runs.len());
+  let mut can_pad = true;
+  for run in runs {
+    if !run.nodes.iter().any(|n| n.is_newline()) {
+      continue;
+    }
+    let (text, is_padding) = run_text(&run, is_object);
+    let holder = whitespace_holder(&run.nodes);
+    if is_padding && holder.is_none() {
+      // both sides are padded or neither is
+      can_pad = false;
+    }
+    changes.push((run.nodes, holder, text, is_padding));
+  }
+
+  for (nodes, holder, text, is_padding) in changes {
+    let text = if is_padding && !can_pad { "" } else { text };
+    for (index, node) in nodes.into_iter().enumerate() {
+      if Some(index) == holder {
+        set_whitespace(&node, text);
+      } else {
+        node.remove();
+      }
+    }
+  }
+}
+
+/// Gets the text that replaces a run with a newline and whether it's the
+/// padding inside an object's braces.
+fn run_text(run: &TriviaRun, is_object: bool) -> (&'static str, bool) {
+  let after_open = is_open_token(run.previous.as_ref());
+  let before_close = is_close_token(&run.next);
+  let is_padding =
+    is_object && (after_open || before_close) && !(after_open && before_close);
+  let text = if is_padding {
+    " "
+  } else if after_open
+    || before_close
+    || run.next.is_comma()
+    || run.next.token_char() == Some(':')
+  {
+    ""
+  } else {
+    " "
+  };
+  (text, is_padding)
+}
+
+/// Gets the index of the node in the run whose text can be set.
+fn whitespace_holder(nodes: &[CstNode]) -> Option<usize> {
+  nodes.iter().position(|n| n.is_whitespace())
+}
+
+/// Adds a node to hold the space of each run that's replaced with a space
+/// but only has newlines, such as before the closing brace of a root object.
+fn add_missing_holders(node: &CstNode, is_object: bool) {
+  let CstNode::Container(container) = node else {
+    return;
+  };
+  for run in trivia_runs(&node.children()) {
+    if run.nodes.iter().any(|n| n.is_newline())
+      && whitespace_holder(&run.nodes).is_none()
+      && run_text(&run, is_object).0 == " "
+    {
+      let whitespace = CstWhitespace::new(String::new());
+      container
+        .insert_children(run.next.child_index(), vec![whitespace.into()]);
+    }
+  }
+}
+
+/// Whitespace, newlines and indentation between two other nodes.
+struct TriviaRun {
+  previous: Option<CstNode>,
+  nodes: Vec<CstNode>,
+  next: CstNode,
+}
+
+fn trivia_runs(children: &[CstNode]) -> Vec<TriviaRun> {
+  let mut runs = Vec::new();
+  let mut previous = None;
+  let mut nodes = Vec::new();
+  for child in children {
+    if child.is_whitespace() || child.is_newline() {
+      nodes.push(child.clone());
+    } else {
+      if !nodes.is_empty() {
+        runs.push(TriviaRun {
+          previous: previous.clone(),
+          nodes: std::mem::take(&mut nodes),
+          next: child.clone(),
+        });
+      }
+      previous = Some(child.clone());
+    }
+  }
+  runs
+}
+
+fn set_whitespace(node: &CstNode, text: &str) {
+  if let Some(whitespace) = node.as_whitespace() {
+    whitespace.set_value(text.to_string());
+  }
+}
+
+/// Gets the comma after the last element of an object or array.
+fn trailing_comma(node: &CstNode) -> Option<CstNode> {
+  if node.as_object().is_none() && node.as_array().is_none() {
+    return None;
+  }
+  node
+    .children()
+    .into_iter()
+    .rev()
+    .skip(1)
+    .find(|c| !c.is_trivia())
+    .filter(|c| c.is_comma())
+}
+
+fn is_open_token(node: Option<&CstNode>) -> bool {
+  node.is_some_and(|n| matches!(n.token_char(), Some('{') | Some('[')))
+}
+
+fn is_close_token(node: &CstNode) -> bool {
+  matches!(node.token_char(), Some('}') | Some(']'))
+}
+
+fn contains_line_comment(node: &CstNode) -> bool {
+  node.children().iter().any(|child| {
+    child.as_comment().is_some_and(|c| c.is_line_comment())
+      || contains_line_comment(child)
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use jsonc_parser::cst::CstRootNode;
+
+  use super::*;
+
+  fn root_container(text: &str) -> (CstRootNode, CstContainerNode) {
+    let root = CstRootNode::parse(text, &ParseOptions::default()).unwrap();
+    let container = match root.value().unwrap() {
+      CstNode::Container(container) => container,
+      CstNode::Leaf(_) => unreachable!(),
+    };
+    (root, container)
+  }
+
+  #[test]
+  fn puts_containers_on_single_line() {
+    let (root, container) = root_container(
+      "{\n  \"a\": [\n    1,\n    2,\n  ],\n  \"b\": {\n    \"c\": 1\n  }\n}",
+    );
+    assert!(is_multiline(&container));
+    ensure_single_line(&container).unwrap();
+    assert!(!is_multiline(&container));
+    assert_eq!(root.to_string(), "{ \"a\": [1, 2], \"b\": { \"c\": 1 } }");
+  }
+
+  #[test]
+  fn replaces_newlines_without_indentation_with_spaces() {
+    let (root, container) =
+      root_container("{\n\"a\": [\n1,\n2,\n],\n\"b\": 2,\n}");
+    ensure_single_line(&container).unwrap();
+    assert_eq!(root.to_string(), "{ \"a\": [1, 2], \"b\": 2 }");
+  }
+
+  #[test]
+  fn fails_with_line_comment() {
+    let text = "[\n  1, // one\n  2\n]";
+    let (root, container) = root_container(text);
+    assert_eq!(
+      ensure_single_line(&container),
+      Err(SingleLineError::LineComment)
+    );
+    assert_eq!(root.to_string(), text);
+  }
+}
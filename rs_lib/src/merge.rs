@@ -0,0 +1,99 @@
+use jsonc_parser::cst::CstObject;
+use jsonc_parser::cst::CstRootNode;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::patch::json_equal;
+use crate::values;
+
+/// Applies a JSON Merge Patch (RFC 7396) to the document.
+///
+/// A patch that isn't an object replaces the entire value.
+pub fn merge_into_root(root: &CstRootNode, patch: &Value) {
+  match (patch, root.object_value()) {
+    (Value::Object(patch), Some(obj)) => merge_into_object(&obj, patch),
+    _ => root.set_value(to_cst_input(patch)),
+  }
+}
+
+/// Applies the members of a JSON Merge Patch (RFC 7396) to the object.
+///
+/// Members with a `null` value are removed, objects are merged recursively,
+/// and other values replace the existing value. Values that are already
+/// equal are left untouched so their formatting is kept.
+pub fn merge_into_object(obj: &CstObject, patch: &Map<String, Value>) {
+  for (name, value) in patch {
+    let prop = obj.get(name);
+    match (value, prop) {
+      (Value::Null, Some(prop)) => prop.remove(),
+      (Value::Null, None) => {}
+      (Value::Object(patch), Some(prop)) => match prop.object_value() {
+        Some(child) => merge_into_object(&child, patch),
+        None => prop.set_value(to_cst_input(value)),
+      },
+      (_, Some(prop)) => {
+        let is_equal = prop
+          .value()
+          .and_then(|v| values::to_serde_value(&v))
+          .is_some_and(|current| json_equal(&current, value));
+        if !is_equal {
+          prop.set_value(to_cst_input(value));
+        }
+      }
+      (_, None) => {
+        obj.append(name, to_cst_input(value));
+      }
+    }
+  }
+}
+
+/// Converts a patch value to an input value, dropping `null` members of
+/// objects since merging into a new object removes them.
+fn to_cst_input(value: &Value) -> jsonc_parser::cst::CstInputValue {
+  values::from_serde_value(without_null_members(value))
+}
+
+fn without_null_members(value: &Value) -> Value {
+  match value {
+    Value::Object(obj) => Value::Object(
+      obj
+        .iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(name, value)| (name.clone(), without_null_members(value)))
+        .collect(),
+    ),
+    _ => value.clone(),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn merges_into_object() {
+    let root = CstRootNode::parse(
+      "{\n  // keep\n  \"a\": 0x1,\n  \"b\": { \"c\": 1, \"d\": 2 },\n  \"e\": 1\n}",
+      &ParseOptions::default(),
+    )
+    .unwrap();
+    merge_into_root(
+      &root,
+      &json!({ "a": 1, "b": { "c": null, "f": { "g": null } }, "e": null }),
+    );
+    assert_eq!(
+      root.to_string(),
+      "{\n  // keep\n  \"a\": 0x1,\n  \"b\": {\n    \"d\": 2,\n    \"f\": {}\n  }\n}"
+    );
+  }
+
+  #[test]
+  fn replaces_non_object_root() {
+    let root = CstRootNode::parse("[1, 2]", &ParseOptions::default()).unwrap();
+    merge_into_root(&root, &json!({ "a": null, "b": 1 }));
+    assert_eq!(root.to_string(), "{\n  \"b\": 1\n}");
+  }
+}
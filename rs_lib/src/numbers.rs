@@ -0,0 +1,417 @@
+use std::fmt;
+
+use jsonc_parser::ParseOptions;
+use jsonc_parser::ast;
+use serde::Serialize;
+use serde::Serializer;
+use serde::ser::SerializeMap;
+use serde::ser::SerializeSeq;
+
+/// Largest integer a JavaScript number can represent exactly.
+pub(crate) const MAX_SAFE_INTEGER: i128 = 9_007_199_254_740_991;
+
+/// How integers outside JavaScript's safe integer range are converted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LargeIntegers {
+  /// Convert to a number, which may lose precision like `JSON.parse`.
+  #[default]
+  Number,
+  /// Convert to a `BigInt`.
+  BigInt,
+  /// Convert to a string of the integer's decimal digits.
+  String,
+}
+
+/// Value of a number literal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+  Integer(i128),
+  Float(f64),
+}
+
+impl Number {
+  pub fn to_f64(self) -> f64 {
+    match self {
+      Number::Integer(value) => value as f64,
+      Number::Float(value) => value,
+    }
+  }
+
+  /// Gets the value as an integer when it's one that can be represented
+  /// exactly.
+  pub fn to_integer(self) -> Option<i128> {
+    match self {
+      Number::Integer(value) => Some(value),
+      Number::Float(value) if value.fract() == 0.0 => {
+        Some(value as i128).filter(|v| is_safe_integer(*v))
+      }
+      Number::Float(_) => None,
+    }
+  }
+}
+
+/// Error that occurs when a number can't be written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberError {
+  /// NaN and infinities can't be represented in JSON.
+  NotFinite(f64),
+  /// The text isn't a number literal.
+  InvalidLiteral(String),
+  /// The number literal isn't an integer.
+  NotInteger(String),
+}
+
+impl fmt::Display for NumberError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      NumberError::NotFinite(value) => {
+        let value = if value.is_nan() {
+          "NaN"
+        } else if *value > 0.0 {
+          "Infinity"
+        } else {
+          "-Infinity"
+        };
+        write!(
+          f,
+          "Cannot write {} because JSON numbers must be finite",
+          value
+        )
+      }
+      NumberError::InvalidLiteral(raw) => {
+        write!(f, "Expected a number literal, but found '{}'", raw)
+      }
+      NumberError::NotInteger(raw) => {
+        write!(f, "Expected an integer, but found '{}'", raw)
+      }
+    }
+  }
+}
+
+/// Checks that the text is a single number literal as jsonc-parser parses
+/// it, including hexadecimal numbers and numbers with a unary plus.
+pub fn validate_literal(raw: &str) -> Result<(), NumberError> {
+  let is_literal = jsonc_parser::parse_to_ast(
+    raw,
+    &Default::default(),
+    &ParseOptions::default(),
+  )
+  .ok()
+  .and_then(|result| result.value)
+  .is_some_and(
+    |value| matches!(value, ast::Value::NumberLit(lit) if lit.value == raw),
+  );
+  if is_literal {
+    Ok(())
+  } else {
+    Err(NumberError::InvalidLiteral(raw.to_string()))
+  }
+}
+
+/// Gets the decimal digits of an integer literal, including integers too
+/// large for an `i128`.
+pub fn integer_digits(raw: &str) -> Result<String, NumberError> {
+  if is_integer_text(raw) {
+    return Ok(raw.trim_start_matches('+').to_string());
+  }
+  parse(raw)
+    .and_then(|number| number.to_integer())
+    .map(|value| value.to_string())
+    .ok_or_else(|| NumberError::NotInteger(raw.to_string()))
+}
+
+/// Writes the number as a literal.
+///
+/// When `style` is the text of the literal being replaced, its style is
+/// kept where possible: hexadecimal integers stay hexadecimal with the same
+/// case, numbers with an exponent keep one, decimals keep at least as many
+/// fraction digits (ex. `1.50`), and a unary plus is kept on positive
+/// numbers.
+pub fn write(
+  value: Number,
+  style: Option<&str>,
+) -> Result<String, NumberError> {
+  if let Number::Float(value) = value
+    && !value.is_finite()
+  {
+    return Err(NumberError::NotFinite(value));
+  }
+  let Some(style) = style else {
+    return Ok(write_plain(value));
+  };
+
+  let is_negative = value.to_f64() < 0.0;
+  let unsigned_style = style.trim_start_matches(['-', '+']);
+  let hex_prefix = unsigned_style
+    .get(..2)
+    .filter(|p| p.eq_ignore_ascii_case("0x"));
+  let mut text = match (hex_prefix, value.to_integer()) {
+    (Some(prefix), Some(integer)) => {
+      let digits =
+        if unsigned_style[2..].bytes().any(|b| b.is_ascii_uppercase()) {
+          format!("{:X}", integer.unsigned_abs())
+        } else {
+          format!("{:x}", integer.unsigned_abs())
+        };
+      let sign = if is_negative { "-" } else { "" };
+      format!("{}{}{}", sign, prefix, digits)
+    }
+    _ if unsigned_style.contains(['e', 'E']) => {
+      write_exponent(value, unsigned_style)
+    }
+    _ => {
+      let text = write_plain(value);
+      let fraction_digits = unsigned_style
+        .split_once('.')
+        .map(|(_, fraction)| fraction.len())
+        .unwrap_or(0);
+      pad_fraction(text, fraction_digits)
+    }
+  };
+  if style.starts_with('+') && !is_negative {
+    text.insert(0, '+');
+  }
+  Ok(text)
+}
+
+/// Writes the number like JavaScript's `String(number)`, but without a `+`
+/// in the exponent.
+fn write_plain(value: Number) -> String {
+  match value {
+    Number::Integer(value) => value.to_string(),
+    Number::Float(value) => {
+      let abs = value.abs();
+      if abs != 0.0 && !(1e-6..1e21).contains(&abs) {
+        format!("{:e}", value)
+      } else if value.fract() == 0.0 {
+        format!("{}", value as i128)
+      } else {
+        value.to_string()
+      }
+    }
+  }
+}
+
+/// Writes the number with an exponent using the case and sign style of the
+/// exponent in `style`.
+fn write_exponent(value: Number, style: &str) -> String {
+  let text = match value {
+    Number::Integer(value) => {
+      let digits = value.unsigned_abs().to_string();
+      let trimmed = digits.trim_end_matches('0');
+      let (mantissa, exponent) = if trimmed.is_empty() {
+        ("0".to_string(), 0)
+      } else if trimmed.len() == 1 {
+        (trimmed.to_string(), digits.len() - 1)
+      } else {
+        (
+          format!("{}.{}", &trimmed[..1], &trimmed[1..]),
+          digits.len() - 1,
+        )
+      };
+      let sign = if value < 0 { "-" } else { "" };
+      format!("{}{}e{}", sign, mantissa, exponent)
+    }
+    Number::Float(value) => format!("{:e}", value),
+  };
+  let text = if style.contains('E') {
+    text.replace('e', "E")
+  } else {
+    text
+  };
+  let has_plus = style.contains("e+") || style.contains("E+");
+  if has_plus && !text.contains("e-") && !text.contains("E-") {
+    text.replacen(['e', 'E'], if style.contains('E') { "E+" } else { "e+" }, 1)
+  } else {
+    text
+  }
+}
+
+/// Pads the decimal with zeros so it has at least the number of fraction
+/// digits.
+fn pad_fraction(mut text: String, fraction_digits: usize) -> String {
+  if fraction_digits == 0 || text.contains(['e', 'E']) {
+    return text;
+  }
+  let current = match text.split_once('.') {
+    Some((_, fraction)) => fraction.len(),
+    None => {
+      text.push('.');
+      0
+    }
+  };
+  for _ in current..fraction_digits {
+    text.push('0');
+  }
+  text
+}
+
+/// Parses the text of a number literal, including hexadecimal numbers and
+/// numbers with a unary plus.
+///
+/// Integers are parsed exactly when they fit in an `i128`.
+pub fn parse(raw: &str) -> Option<Number> {
+  let (is_negative, unsigned) = match raw.strip_prefix('-') {
+    Some(rest) => (true, rest),
+    None => (false, raw.strip_prefix('+').unwrap_or(raw)),
+  };
+  let hex = unsigned
+    .strip_prefix("0x")
+    .or_else(|| unsigned.strip_prefix("0X"));
+  let magnitude = match hex {
+    Some(hex) => i128::from_str_radix(hex, 16).ok(),
+    None if unsigned.bytes().all(|b| b.is_ascii_digit()) => {
+      unsigned.parse::<i128>().ok()
+    }
+    None => None,
+  };
+  match magnitude {
+    Some(value) => {
+      Some(Number::Integer(if is_negative { -value } else { value }))
+    }
+    None => {
+      let value = unsigned.parse::<f64>().ok()?;
+      Some(Number::Float(if is_negative { -value } else { value }))
+    }
+  }
+}
+
+/// Gets if the integer can be represented exactly by a JavaScript number.
+pub(crate) fn is_safe_integer(value: i128) -> bool {
+  (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&value)
+}
+
+/// A parsed value that serializes its numbers according to how large
+/// integers should be converted.
+pub struct SerializableValue<'a> {
+  value: &'a ast::Value<'a>,
+  large_integers: LargeIntegers,
+}
+
+impl<'a> SerializableValue<'a> {
+  pub fn new(value: &'a ast::Value<'a>, large_integers: LargeIntegers) -> Self {
+    Self {
+      value,
+      large_integers,
+    }
+  }
+
+  fn child(&self, value: &'a ast::Value<'a>) -> Self {
+    Self::new(value, self.large_integers)
+  }
+}
+
+impl Serialize for SerializableValue<'_> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self.value {
+      ast::Value::NullKeyword(_) => serializer.serialize_unit(),
+      ast::Value::BooleanLit(lit) => serializer.serialize_bool(lit.value),
+      ast::Value::StringLit(lit) => serializer.serialize_str(&lit.value),
+      ast::Value::NumberLit(lit) => {
+        serialize_number(lit.value, self.large_integers, serializer)
+      }
+      ast::Value::Array(array) => {
+        let mut seq = serializer.serialize_seq(Some(array.elements.len()))?;
+        for element in &array.elements {
+          seq.serialize_element(&self.child(element))?;
+        }
+        seq.end()
+      }
+      ast::Value::Object(obj) => {
+        let mut map = serializer.serialize_map(Some(obj.properties.len()))?;
+        for prop in &obj.properties {
+          map.serialize_entry(prop.name.as_str(), &self.child(&prop.value))?;
+        }
+        map.end()
+      }
+    }
+  }
+}
+
+fn serialize_number<S: Serializer>(
+  raw: &str,
+  large_integers: LargeIntegers,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  match (parse(raw), large_integers) {
+    (Some(Number::Integer(value)), _) if is_safe_integer(value) => {
+      serializer.serialize_f64(value as f64)
+    }
+    (Some(Number::Integer(value)), LargeIntegers::BigInt) => {
+      serializer.serialize_i128(value)
+    }
+    (Some(Number::Integer(value)), LargeIntegers::String) => {
+      serializer.serialize_str(&value.to_string())
+    }
+    (Some(Number::Integer(value)), LargeIntegers::Number) => {
+      serializer.serialize_f64(value as f64)
+    }
+    // integers too large for an i128
+    (Some(Number::Float(_)), LargeIntegers::String) if is_integer_text(raw) => {
+      serializer.serialize_str(raw.trim_start_matches('+'))
+    }
+    (Some(Number::Float(value)), _) => serializer.serialize_f64(value),
+    (None, _) => serializer.serialize_str(raw),
+  }
+}
+
+fn is_integer_text(raw: &str) -> bool {
+  let digits = raw.trim_start_matches(['-', '+']);
+  !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parses_literals() {
+    assert_eq!(parse("-0x1F"), Some(Number::Integer(-31)));
+    assert_eq!(parse("+5"), Some(Number::Integer(5)));
+    assert_eq!(parse("1.5e2"), Some(Number::Float(150.0)));
+    assert_eq!(
+      parse("123456789012345678901234567890"),
+      Some(Number::Integer(123456789012345678901234567890))
+    );
+    assert_eq!(parse("abc"), None);
+  }
+
+  #[test]
+  fn writes_numbers() {
+    assert_eq!(write(Number::Float(0.1), None).unwrap(), "0.1");
+    assert_eq!(write(Number::Float(1e21), None).unwrap(), "1e21");
+    assert_eq!(write(Number::Float(2.0), None).unwrap(), "2");
+    assert_eq!(
+      write(Number::Float(f64::NAN), None)
+        .unwrap_err()
+        .to_string(),
+      "Cannot write NaN because JSON numbers must be finite"
+    );
+  }
+
+  #[test]
+  fn writes_numbers_in_existing_style() {
+    let write_as = |value, style| write(value, Some(style)).unwrap();
+    assert_eq!(write_as(Number::Integer(255), "0x0a"), "0xff");
+    assert_eq!(write_as(Number::Integer(255), "0X0A"), "0XFF");
+    assert_eq!(write_as(Number::Integer(1500), "1E+3"), "1.5E+3");
+    assert_eq!(write_as(Number::Float(2.5), "1.00"), "2.50");
+    assert_eq!(write_as(Number::Integer(2), "+1"), "+2");
+    assert_eq!(write_as(Number::Integer(-2), "+1"), "-2");
+  }
+
+  #[test]
+  fn validates_literals() {
+    assert!(validate_literal("0x1F").is_ok());
+    assert!(validate_literal("+1").is_ok());
+    assert_eq!(
+      validate_literal("1 2").unwrap_err().to_string(),
+      "Expected a number literal, but found '1 2'"
+    );
+    assert_eq!(integer_digits("+12").unwrap(), "12");
+    assert_eq!(
+      integer_digits("1.5"),
+      Err(NumberError::NotInteger("1.5".to_string()))
+    );
+  }
+}
@@ -0,0 +1,431 @@
+use std::fmt;
+
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstRootNode;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::pointer;
+use crate::pointer::PointerError;
+use crate::values;
+use crate::values::DetachedNode;
+use crate::values::InsertValue;
+
+/// A JSON Patch (RFC 6902) operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchOperation {
+  Add { path: String, value: Value },
+  Remove { path: String },
+  Replace { path: String, value: Value },
+  Move { from: String, path: String },
+  Copy { from: String, path: String },
+  Test { path: String, value: Value },
+}
+
+impl PatchOperation {
+  /// Reads an operation from its JSON representation.
+  pub fn from_value(value: &Value) -> Result<Self, String> {
+    let Some(obj) = value.as_object() else {
+      return Err("Expected an operation object".to_string());
+    };
+    let string_member = |name: &str| match obj.get(name) {
+      Some(Value::String(value)) => Ok(value.clone()),
+      Some(_) => Err(format!("Expected '{}' to be a string", name)),
+      None => Err(format!("Missing '{}'", name)),
+    };
+    let value_member = || {
+      obj
+        .get("value")
+        .cloned()
+        .ok_or_else(|| "Missing 'value'".to_string())
+    };
+    let op = string_member("op")?;
+    Ok(match op.as_str() {
+      "add" => PatchOperation::Add {
+        path: string_member("path")?,
+        value: value_member()?,
+      },
+      "remove" => PatchOperation::Remove {
+        path: string_member("path")?,
+      },
+      "replace" => PatchOperation::Replace {
+        path: string_member("path")?,
+        value: value_member()?,
+      },
+      "move" => PatchOperation::Move {
+        from: string_member("from")?,
+        path: string_member("path")?,
+      },
+      "copy" => PatchOperation::Copy {
+        from: string_member("from")?,
+        path: string_member("path")?,
+      },
+      "test" => PatchOperation::Test {
+        path: string_member("path")?,
+        value: value_member()?,
+      },
+      _ => return Err(format!("Unknown operation '{}'", op)),
+    })
+  }
+
+  /// Gets the JSON representation of the operation.
+  pub fn to_value(&self) -> Value {
+    match self {
+      PatchOperation::Add { path, value } => {
+        json!({ "op": "add", "path": path, "value": value })
+      }
+      PatchOperation::Remove { path } => {
+        json!({ "op": "remove", "path": path })
+      }
+      PatchOperation::Replace { path, value } => {
+        json!({ "op": "replace", "path": path, "value": value })
+      }
+      PatchOperation::Move { from, path } => {
+        json!({ "op": "move", "from": from, "path": path })
+      }
+      PatchOperation::Copy { from, path } => {
+        json!({ "op": "copy", "from": from, "path": path })
+      }
+      PatchOperation::Test { path, value } => {
+        json!({ "op": "test", "path": path, "value": value })
+      }
+    }
+  }
+}
+
+/// Error that occurs when a patch can't be applied.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchError {
+  /// The operation at the index failed.
+  Operation { index: usize, kind: PatchErrorKind },
+  /// The document's text can't be parsed to apply the patch to a copy.
+  InvalidDocument(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchErrorKind {
+  /// The operation is malformed.
+  InvalidOperation(String),
+  /// A pointer in the operation is invalid or can't be applied.
+  Pointer(PointerError),
+  /// Nothing exists at the pointer.
+  NotFound { pointer: String },
+  /// The value at the pointer doesn't equal the expected value.
+  TestFailed { pointer: String },
+  /// A value can't be moved into one of its own children.
+  MoveIntoSelf { from: String, path: String },
+  /// The value at the pointer can't be copied.
+  InvalidValue { pointer: String },
+}
+
+impl fmt::Display for PatchError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      PatchError::Operation { index, kind } => {
+        write!(f, "Patch operation {} failed: {}", index, kind)
+      }
+      PatchError::InvalidDocument(message) => {
+        write!(f, "Patch could not be applied: {}", message)
+      }
+    }
+  }
+}
+
+impl fmt::Display for PatchErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      PatchErrorKind::InvalidOperation(message) => write!(f, "{}", message),
+      PatchErrorKind::Pointer(err) => write!(f, "{}", err),
+      PatchErrorKind::NotFound { pointer } => {
+        write!(f, "No value exists at '{}'", pointer)
+      }
+      PatchErrorKind::TestFailed { pointer } => {
+        write!(
+          f,
+          "Value at '{}' does not equal the expected value",
+          pointer
+        )
+      }
+      PatchErrorKind::MoveIntoSelf { from, path } => {
+        write!(f, "Cannot move '{}' into its own child '{}'", from, path)
+      }
+      PatchErrorKind::InvalidValue { pointer } => {
+        write!(f, "Value at '{}' cannot be copied", pointer)
+      }
+    }
+  }
+}
+
+/// Reads the operations of a patch from its JSON representation.
+pub fn parse_operations(
+  values: &[Value],
+) -> Result<Vec<PatchOperation>, PatchError> {
+  values
+    .iter()
+    .enumerate()
+    .map(|(index, value)| {
+      PatchOperation::from_value(value).map_err(|message| {
+        PatchError::Operation {
+          index,
+          kind: PatchErrorKind::InvalidOperation(message),
+        }
+      })
+    })
+    .collect()
+}
+
+/// Applies the operations to the document.
+///
+/// The patch is applied atomically. It is first applied to a copy of the
+/// document and only applied to the document once every operation has
+/// succeeded, so a failed patch leaves the document unchanged.
+///
+/// Moved and copied values keep the comments within them, and a moved value
+/// also keeps the comments around it.
+pub fn apply(
+  root: &CstRootNode,
+  operations: &[PatchOperation],
+) -> Result<(), PatchError> {
+  let copy = CstRootNode::parse(&root.to_string(), root.parse_options())
+    .map_err(|err| PatchError::InvalidDocument(err.to_string()))?;
+  apply_operations(&copy, operations)?;
+  apply_operations(root, operations)
+}
+
+fn apply_operations(
+  root: &CstRootNode,
+  operations: &[PatchOperation],
+) -> Result<(), PatchError> {
+  for (index, operation) in operations.iter().enumerate() {
+    apply_operation(root, operation)
+      .map_err(|kind| PatchError::Operation { index, kind })?;
+  }
+  Ok(())
+}
+
+fn apply_operation(
+  root: &CstRootNode,
+  operation: &PatchOperation,
+) -> Result<(), PatchErrorKind> {
+  match operation {
+    PatchOperation::Add { path, value } => {
+      add(root, path, InsertValue::from(value.clone()))
+    }
+    PatchOperation::Remove { path } => {
+      let node = find(root, path)?;
+      pointer::remove_value(node);
+      Ok(())
+    }
+    PatchOperation::Replace { path, value } => {
+      let node = find(root, path)?;
+      pointer::replace_value(node, values::from_serde_value(value.clone()));
+      Ok(())
+    }
+    PatchOperation::Move { from, path } => {
+      let node = find(root, from)?;
+      if from == path {
+        return Ok(());
+      }
+      if path.starts_with(&format!("{}/", from)) {
+        return Err(PatchErrorKind::MoveIntoSelf {
+          from: from.clone(),
+          path: path.clone(),
+        });
+      }
+      let value = DetachedNode::remove_value(&node);
+      add(root, path, value.into())
+    }
+    PatchOperation::Copy { from, path } => {
+      let node = find(root, from)?;
+      let value = InsertValue::copy_of(&node).map_err(|_| {
+        PatchErrorKind::InvalidValue {
+          pointer: from.clone(),
+        }
+      })?;
+      add(root, path, value)
+    }
+    PatchOperation::Test { path, value } => {
+      let node = find(root, path)?;
+      let is_equal = values::to_serde_value(&node)
+        .is_some_and(|actual| json_equal(&actual, value));
+      if is_equal {
+        Ok(())
+      } else {
+        Err(PatchErrorKind::TestFailed {
+          pointer: path.clone(),
+        })
+      }
+    }
+  }
+}
+
+fn find(root: &CstRootNode, path: &str) -> Result<CstNode, PatchErrorKind> {
+  let tokens = pointer::parse(path).map_err(PatchErrorKind::Pointer)?;
+  pointer::resolve(root, &tokens).ok_or_else(|| PatchErrorKind::NotFound {
+    pointer: path.to_string(),
+  })
+}
+
+fn add(
+  root: &CstRootNode,
+  path: &str,
+  value: InsertValue,
+) -> Result<(), PatchErrorKind> {
+  let tokens = pointer::parse(path).map_err(PatchErrorKind::Pointer)?;
+  let Some((last, parent_tokens)) = tokens.split_last() else {
+    value.set_root_value(root);
+    return Ok(());
+  };
+  let parent_pointer = pointer::format(parent_tokens);
+  let parent = pointer::resolve(root, parent_tokens).ok_or_else(|| {
+    PatchErrorKind::NotFound {
+      pointer: parent_pointer.clone(),
+    }
+  })?;
+  match parent {
+    CstNode::Container(CstContainerNode::Object(obj)) => {
+      match obj.get(last) {
+        Some(prop) => {
+          value.set_property_value(&prop);
+        }
+        None => {
+          let index = obj.properties().len();
+          value.insert_property(&obj, index, last);
+        }
+      }
+      Ok(())
+    }
+    CstNode::Container(CstContainerNode::Array(array)) => {
+      let len = array.elements().len();
+      let index = if last == "-" {
+        Some(len)
+      } else {
+        pointer::parse_array_index(last)
+      };
+      match index {
+        Some(index) if index <= len => {
+          value.insert_element(&array, index);
+          Ok(())
+        }
+        _ => Err(PatchErrorKind::Pointer(PointerError::InvalidIndex {
+          pointer: parent_pointer,
+          token: last.clone(),
+        })),
+      }
+    }
+    _ => Err(PatchErrorKind::Pointer(PointerError::NotContainer {
+      pointer: parent_pointer,
+    })),
+  }
+}
+
+/// Compares JSON values, treating numbers as equal when they have the
+/// same numeric value (ex. `1` and `1.0`).
+pub(crate) fn json_equal(a: &Value, b: &Value) -> bool {
+  match (a, b) {
+    // integers are compared exactly so digits an `f64` can't represent
+    // aren't lost
+    (Value::Number(a), Value::Number(b)) if a.is_f64() || b.is_f64() => {
+      a.as_f64() == b.as_f64()
+    }
+    (Value::Array(a), Value::Array(b)) => {
+      a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_equal(a, b))
+    }
+    (Value::Object(a), Value::Object(b)) => {
+      a.len() == b.len()
+        && a
+          .iter()
+          .all(|(key, a)| b.get(key).is_some_and(|b| json_equal(a, b)))
+    }
+    _ => a == b,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+
+  use super::*;
+
+  fn parse_root(text: &str) -> CstRootNode {
+    CstRootNode::parse(text, &ParseOptions::default()).unwrap()
+  }
+
+  #[test]
+  fn applies_operations() {
+    let root = parse_root(r#"{ "a": [1, 2], "b": { "c": 1 } }"#);
+    let operations = parse_operations(&[
+      json!({ "op": "test", "path": "/a/0", "value": 1 }),
+      json!({ "op": "replace", "path": "/a/0", "value": 3 }),
+      json!({ "op": "copy", "from": "/b", "path": "/d" }),
+      json!({ "op": "move", "from": "/b/c", "path": "/e" }),
+      json!({ "op": "remove", "path": "/b" }),
+    ])
+    .unwrap();
+    apply(&root, &operations).unwrap();
+    assert_eq!(
+      values::to_serde_value(&root.value().unwrap()).unwrap(),
+      json!({ "a": [3, 2], "d": { "c": 1 }, "e": 1 })
+    );
+  }
+
+  #[test]
+  fn moves_and_copies_values_with_their_comments() {
+    let root = parse_root(
+      "{\n  // about a\n  \"a\": {\n    \"b\": 1 // one\n  },\n  \"c\": [\n    true\n  ]\n}",
+    );
+    let operations = parse_operations(&[
+      json!({ "op": "copy", "from": "/a", "path": "/c/0" }),
+      json!({ "op": "move", "from": "/a", "path": "/d" }),
+    ])
+    .unwrap();
+    apply(&root, &operations).unwrap();
+    assert_eq!(
+      root.to_string(),
+      "{\n  \"c\": [\n    {\n      \"b\": 1 // one\n    },\n    true\n  ],\n  // about a\n  \"d\": {\n    \"b\": 1 // one\n  }\n}"
+    );
+  }
+
+  #[test]
+  fn failed_patch_leaves_document_unchanged() {
+    let text = r#"{ "a": 1 }"#;
+    let root = parse_root(text);
+    let operations = parse_operations(&[
+      json!({ "op": "remove", "path": "/a" }),
+      json!({ "op": "test", "path": "/a", "value": 1 }),
+    ])
+    .unwrap();
+    assert_eq!(
+      apply(&root, &operations),
+      Err(PatchError::Operation {
+        index: 1,
+        kind: PatchErrorKind::NotFound {
+          pointer: "/a".to_string(),
+        },
+      })
+    );
+    assert_eq!(root.to_string(), text);
+  }
+
+  #[test]
+  fn rejects_invalid_operations() {
+    let err =
+      parse_operations(&[json!({ "op": "other", "path": "" })]).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Patch operation 0 failed: Unknown operation 'other'"
+    );
+  }
+
+  #[test]
+  fn compares_numbers_by_value() {
+    assert!(json_equal(&json!(1), &json!(1.0)));
+    assert!(json_equal(&json!({ "a": [1] }), &json!({ "a": [1.0] })));
+    assert!(!json_equal(
+      &json!(9007199254740993u64),
+      &json!(9007199254740992u64)
+    ));
+    assert!(!json_equal(&json!({ "a": 1 }), &json!({ "a": 1, "b": 2 })));
+  }
+}
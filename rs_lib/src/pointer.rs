@@ -0,0 +1,398 @@
+use std::fmt;
+
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstInputValue;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstRootNode;
+
+use crate::values;
+use crate::values::InsertValue;
+
+/// Error that occurs when a JSON Pointer can't be parsed or applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointerError {
+  /// The pointer is not a valid RFC 6901 JSON Pointer.
+  InvalidSyntax { pointer: String, message: String },
+  /// The value at the pointer is not an object or array.
+  NotContainer { pointer: String },
+  /// The token is not a valid index for the array at the pointer.
+  InvalidIndex { pointer: String, token: String },
+}
+
+impl fmt::Display for PointerError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      PointerError::InvalidSyntax { pointer, message } => {
+        write!(f, "Invalid JSON Pointer '{}': {}", pointer, message)
+      }
+      PointerError::NotContainer { pointer } => {
+        write!(f, "Expected an object or array at '{}'", pointer)
+      }
+      PointerError::InvalidIndex { pointer, token } => {
+        write!(
+          f,
+          "Invalid array index '{}' for array at '{}'",
+          token, pointer
+        )
+      }
+    }
+  }
+}
+
+/// Parses a JSON Pointer into its unescaped reference tokens.
+pub fn parse(pointer: &str) -> Result<Vec<String>, PointerError> {
+  if pointer.is_empty() {
+    return Ok(Vec::new());
+  }
+  let Some(rest) = pointer.strip_prefix('/') else {
+    return Err(PointerError::InvalidSyntax {
+      pointer: pointer.to_string(),
+      message: "must be empty or start with '/'".to_string(),
+    });
+  };
+  rest
+    .split('/')
+    .map(|token| {
+      unescape_token(token).ok_or_else(|| PointerError::InvalidSyntax {
+        pointer: pointer.to_string(),
+        message: "'~' must be followed by '0' or '1'".to_string(),
+      })
+    })
+    .collect()
+}
+
+/// Formats reference tokens as a JSON Pointer, escaping them as necessary.
+pub fn format<T: AsRef<str>>(tokens: &[T]) -> String {
+  let mut pointer = String::new();
+  for token in tokens {
+    pointer.push('/');
+    pointer.push_str(&token.as_ref().replace('~', "~0").replace('/', "~1"));
+  }
+  pointer
+}
+
+fn unescape_token(token: &str) -> Option<String> {
+  let mut result = String::with_capacity(token.len());
+  let mut chars = token.chars();
+  while let Some(c) = chars.next() {
+    if c == '~' {
+      match chars.next() {
+        Some('0') => result.push('~'),
+        Some('1') => result.push('/'),
+        _ => return None,
+      }
+    } else {
+      result.push(c);
+    }
+  }
+  Some(result)
+}
+
+/// Parses an array index token, which must be a non-negative integer
+/// without leading zeros.
+pub(crate) fn parse_array_index(token: &str) -> Option<usize> {
+  let is_valid = !token.is_empty()
+    && token.bytes().all(|b| b.is_ascii_digit())
+    && (token == "0" || !token.starts_with('0'));
+  if is_valid { token.parse().ok() } else { None }
+}
+
+/// Gets the child value of an object or array value for a reference token.
+pub(crate) fn child_value(node: &CstNode, token: &str) -> Option<CstNode> {
+  match node {
+    CstNode::Container(CstContainerNode::Object(obj)) => {
+      obj.get(token)?.value()
+    }
+    CstNode::Container(CstContainerNode::Array(array)) => {
+      array.elements().get(parse_array_index(token)?).cloned()
+    }
+    _ => None,
+  }
+}
+
+/// Resolves the reference tokens to a value in the document.
+pub(crate) fn resolve<T: AsRef<str>>(
+  root: &CstRootNode,
+  tokens: &[T],
+) -> Option<CstNode> {
+  let mut current = root.value()?;
+  for token in tokens {
+    current = child_value(&current, token.as_ref())?;
+  }
+  Some(current)
+}
+
+/// Gets the value at the pointer.
+pub fn get(
+  root: &CstRootNode,
+  pointer: &str,
+) -> Result<Option<CstNode>, PointerError> {
+  Ok(resolve(root, &parse(pointer)?))
+}
+
+/// Sets the value at the pointer.
+///
+/// Existing values are replaced and missing object properties are added,
+/// including any missing intermediate objects. For arrays, the token may
+/// be an existing index, the length of the array, or `-` to append.
+///
+/// The value is a `serde_json::Value`, or a `values::InsertValue` to insert
+/// a copy of another node as is.
+pub fn set(
+  root: &CstRootNode,
+  pointer: &str,
+  value: impl Into<InsertValue>,
+) -> Result<(), PointerError> {
+  set_value(root, pointer, value.into()).map(|_| ())
+}
+
+/// Sets the value at the pointer and gets the inserted value node.
+fn set_value(
+  root: &CstRootNode,
+  pointer: &str,
+  value: InsertValue,
+) -> Result<CstNode, PointerError> {
+  let tokens = parse(pointer)?;
+  let Some((last, parent_tokens)) = tokens.split_last() else {
+    return Ok(value.set_root_value(root));
+  };
+  let mut current: CstNode = match root.value() {
+    Some(value) => value,
+    None => root.object_value_or_set().into(),
+  };
+  for (i, token) in parent_tokens.iter().enumerate() {
+    current = match child_value(&current, token) {
+      Some(child) => child,
+      None => match &current {
+        CstNode::Container(CstContainerNode::Object(obj)) => obj
+          .append(token, CstInputValue::Object(Vec::new()))
+          .value()
+          .unwrap(),
+        CstNode::Container(CstContainerNode::Array(_)) => {
+          return Err(PointerError::InvalidIndex {
+            pointer: format(&tokens[..i]),
+            token: token.clone(),
+          });
+        }
+        _ => {
+          return Err(PointerError::NotContainer {
+            pointer: format(&tokens[..i]),
+          });
+        }
+      },
+    };
+  }
+
+  match &current {
+    CstNode::Container(CstContainerNode::Object(obj)) => match obj.get(last) {
+      Some(prop) => Ok(value.set_property_value(&prop)),
+      None => {
+        let index = obj.properties().len();
+        Ok(value.insert_property(obj, index, last).value().unwrap())
+      }
+    },
+    CstNode::Container(CstContainerNode::Array(array)) => {
+      let elements = array.elements();
+      let index = if last == "-" {
+        Some(elements.len())
+      } else {
+        parse_array_index(last)
+      };
+      match index {
+        Some(index) if index < elements.len() => {
+          Ok(value.replace(elements[index].clone()).unwrap())
+        }
+        Some(index) if index == elements.len() => {
+          Ok(value.insert_element(array, index))
+        }
+        _ => Err(PointerError::InvalidIndex {
+          pointer: format(parent_tokens),
+          token: last.clone(),
+        }),
+      }
+    }
+    _ => Err(PointerError::NotContainer {
+      pointer: format(parent_tokens),
+    }),
+  }
+}
+
+/// Removes the value at the pointer, along with its object property when
+/// the value is in an object.
+///
+/// Returns `false` when nothing exists at the pointer.
+pub fn remove(root: &CstRootNode, pointer: &str) -> Result<bool, PointerError> {
+  let tokens = parse(pointer)?;
+  let Some(node) = resolve(root, &tokens) else {
+    return Ok(false);
+  };
+  remove_value(node);
+  Ok(true)
+}
+
+/// Removes a value node, along with its object property when the value is
+/// in an object.
+pub(crate) fn remove_value(node: CstNode) {
+  match node.parent() {
+    Some(CstContainerNode::ObjectProp(prop)) => values::remove(prop.into()),
+    _ => values::remove(node),
+  }
+}
+
+/// Replaces a value node with a new value and gets the new value's node.
+pub(crate) fn replace_value(
+  node: CstNode,
+  value: CstInputValue,
+) -> Option<CstNode> {
+  match node.parent() {
+    Some(CstContainerNode::ObjectProp(prop)) => {
+      prop.set_value(value);
+      prop.value()
+    }
+    Some(CstContainerNode::Root(root)) => {
+      root.set_value(value);
+      root.value()
+    }
+    _ => match node {
+      CstNode::Container(CstContainerNode::Object(n)) => n.replace_with(value),
+      CstNode::Container(CstContainerNode::Array(n)) => n.replace_with(value),
+      CstNode::Leaf(leaf) => {
+        use jsonc_parser::cst::CstLeafNode;
+        match leaf {
+          CstLeafNode::BooleanLit(n) => n.replace_with(value),
+          CstLeafNode::NullKeyword(n) => n.replace_with(value),
+          CstLeafNode::NumberLit(n) => n.replace_with(value),
+          CstLeafNode::StringLit(n) => n.replace_with(value),
+          CstLeafNode::WordLit(n) => n.replace_with(value),
+          _ => None,
+        }
+      }
+      _ => None,
+    },
+  }
+}
+
+/// Computes the JSON Pointer of a node.
+///
+/// Object properties and property names resolve to the pointer of the
+/// property's value. Trivia and tokens resolve to the pointer of the value
+/// containing them. Returns `None` when the node is not attached to the
+/// root's document.
+pub fn pointer_of(root: &CstRootNode, node: &CstNode) -> Option<String> {
+  let node_root = node.as_root_node().or_else(|| node.root_node())?;
+  if !node_root.ptr_eq(root) {
+    return None;
+  }
+  if node.as_root_node().is_some() {
+    return Some(String::new());
+  }
+  let mut tokens = Vec::new();
+  if let Some(prop) = node.as_object_prop() {
+    tokens.push(prop.name()?.decoded_value().ok()?);
+  }
+  let mut current = node.clone();
+  loop {
+    match current.parent()? {
+      CstContainerNode::Root(_) => break,
+      CstContainerNode::ObjectProp(prop) => {
+        tokens.push(prop.name()?.decoded_value().ok()?);
+      }
+      CstContainerNode::Array(_) => {
+        if let Some(index) = values::element_index(&current) {
+          tokens.push(index.to_string());
+        }
+      }
+      CstContainerNode::Object(_) => {}
+    }
+    current = current.parent()?.into();
+  }
+  tokens.reverse();
+  Some(format(&tokens))
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use serde_json::json;
+
+  use super::*;
+
+  fn parse_root(text: &str) -> CstRootNode {
+    CstRootNode::parse(text, &ParseOptions::default()).unwrap()
+  }
+
+  #[test]
+  fn parses_and_formats_pointers() {
+    assert_eq!(parse("").unwrap(), Vec::<String>::new());
+    assert_eq!(parse("/a~1b/c~0d/").unwrap(), vec!["a/b", "c~d", ""]);
+    assert!(matches!(
+      parse("a"),
+      Err(PointerError::InvalidSyntax { .. })
+    ));
+    assert!(matches!(
+      parse("/a~2"),
+      Err(PointerError::InvalidSyntax { .. })
+    ));
+    assert_eq!(format(&["a/b", "c~d", ""]), "/a~1b/c~0d/");
+  }
+
+  #[test]
+  fn gets_values() {
+    let root = parse_root(r#"{ "a": [1, { "b": true }] }"#);
+    let node = get(&root, "/a/1/b").unwrap().unwrap();
+    assert_eq!(node.to_string(), "true");
+    assert_eq!(pointer_of(&root, &node).unwrap(), "/a/1/b");
+    let other = parse_root(r#"{ "a": [1, { "b": false }] }"#);
+    assert!(pointer_of(&other, &node).is_none());
+    let same_text = parse_root(r#"{ "a": [1, { "b": true }] }"#);
+    assert!(pointer_of(&same_text, &node).is_none());
+    assert!(get(&root, "/a/2").unwrap().is_none());
+    assert!(get(&root, "/a/01").unwrap().is_none());
+  }
+
+  #[test]
+  fn sets_values_creating_objects() {
+    let root = parse_root("{\n  // comment\n  \"a\": [1]\n}");
+    set(&root, "/a/-", json!(2)).unwrap();
+    set(&root, "/b/c", json!(true)).unwrap();
+    assert_eq!(
+      root.to_string(),
+      "{\n  // comment\n  \"a\": [1, 2],\n  \"b\": {\n    \"c\": true\n  }\n}"
+    );
+    assert_eq!(
+      set(&root, "/a/5", json!(null)),
+      Err(PointerError::InvalidIndex {
+        pointer: "/a".to_string(),
+        token: "5".to_string(),
+      })
+    );
+    assert_eq!(
+      set(&root, "/b/c/d", json!(null)),
+      Err(PointerError::NotContainer {
+        pointer: "/b/c".to_string(),
+      })
+    );
+    assert_eq!(
+      values::to_serde_value(&root.value().unwrap()).unwrap(),
+      json!({ "a": [1, 2], "b": { "c": true } })
+    );
+  }
+
+  #[test]
+  fn removes_values() {
+    let root = parse_root(r#"{ "a": 1, "b": [1, 2] }"#);
+    assert!(remove(&root, "/b/1").unwrap());
+    assert!(remove(&root, "/a").unwrap());
+    assert!(!remove(&root, "/c").unwrap());
+    assert_eq!(root.to_string(), r#"{ "b": [1] }"#);
+
+    let root = parse_root(r#"{ "a": [1, 2], "b": 3 }"#);
+    assert!(remove(&root, "/a/0").unwrap());
+    assert!(remove(&root, "/a").unwrap());
+    assert_eq!(root.to_string(), r#"{ "b": 3 }"#);
+
+    let root = parse_root("[\n  1,\n  2\n]");
+    assert!(remove(&root, "/0").unwrap());
+    assert_eq!(root.to_string(), "[\n  2\n]");
+  }
+}
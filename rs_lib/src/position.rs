@@ -0,0 +1,382 @@
+use std::fmt::Write;
+
+use jsonc_parser::cst::CstNode;
+use serde::Serialize;
+
+/// Start and end offsets of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TextRange {
+  pub start: usize,
+  pub end: usize,
+}
+
+/// A 1-based line and column. The column is measured in UTF-16 code
+/// units so it lines up with JavaScript string indexes and editors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LineColumn {
+  pub line: usize,
+  pub column: usize,
+}
+
+/// Converts a byte offset in the text to a UTF-16 offset.
+pub(crate) fn utf16_offset(text: &str, byte_offset: usize) -> usize {
+  text[..clamp_to_char_boundary(text, byte_offset)]
+    .encode_utf16()
+    .count()
+}
+
+/// Converts a byte range in the text to a UTF-16 range.
+pub(crate) fn utf16_range(text: &str, range: TextRange) -> TextRange {
+  let start = utf16_offset(text, range.start);
+  let end = start
+    + text[clamp_to_char_boundary(text, range.start)
+      ..clamp_to_char_boundary(text, range.end)]
+      .encode_utf16()
+      .count();
+  TextRange { start, end }
+}
+
+/// Gets the 1-based line and column of a byte offset in the text.
+pub fn line_column(text: &str, byte_offset: usize) -> LineColumn {
+  let byte_offset = clamp_to_char_boundary(text, byte_offset);
+  let line_start = line_start_offset(text, byte_offset);
+  LineColumn {
+    line: text[..line_start].matches('\n').count() + 1,
+    column: text[line_start..byte_offset].encode_utf16().count() + 1,
+  }
+}
+
+/// Renders the lines surrounding the range with the range underlined.
+pub fn code_frame(text: &str, range: TextRange) -> String {
+  let start = clamp_to_char_boundary(text, range.start);
+  let end = clamp_to_char_boundary(text, range.end.max(range.start));
+  let lines = text.split('\n').collect::<Vec<_>>();
+  let error_line_index = text[..start].matches('\n').count();
+  let first_line_index = error_line_index.saturating_sub(1);
+  let last_line_index = (error_line_index + 1).min(lines.len() - 1);
+  let gutter_width = (last_line_index + 1).to_string().len();
+
+  let line_start = line_start_offset(text, start);
+  let line_text = lines[error_line_index].trim_end_matches('\r');
+  // the start may be past a `\r` that was trimmed from the line
+  let underline_start = (start - line_start).min(line_text.len());
+  let underline_end = (end - line_start).min(line_text.len());
+  let padding = line_text[..underline_start]
+    .chars()
+    .map(|c| if c == '\t' { '\t' } else { ' ' })
+    .collect::<String>();
+  let underline_len = line_text
+    .get(underline_start..underline_end)
+    .map(|t| t.chars().count())
+    .unwrap_or(0)
+    .max(1);
+
+  let mut output = String::new();
+  for (index, line) in lines
+    .iter()
+    .enumerate()
+    .take(last_line_index + 1)
+    .skip(first_line_index)
+  {
+    let marker = if index == error_line_index { ">" } else { " " };
+    let line = line.trim_end_matches('\r');
+    output.push_str(&format!(
+      "{} {:>width$} |{}{}\n",
+      marker,
+      index + 1,
+      if line.is_empty() { "" } else { " " },
+      line,
+      width = gutter_width
+    ));
+    if index == error_line_index {
+      output.push_str(&format!(
+        "  {} | {}{}\n",
+        " ".repeat(gutter_width),
+        padding,
+        "^".repeat(underline_len)
+      ));
+    }
+  }
+  output.truncate(output.trim_end().len());
+  output
+}
+
+/// Describes a parse error with where it starts in the text (ex.
+/// `Unterminated string literal on line 2 column 8`), which is the message of
+/// `ParseError` in the JavaScript API.
+pub fn parse_error_message(
+  err: &jsonc_parser::errors::ParseError,
+  text: &str,
+) -> String {
+  let start = line_column(text, err.range().start);
+  format!(
+    "{} on line {} column {}",
+    err.kind(),
+    start.line,
+    start.column
+  )
+}
+
+/// Gets the byte range of the node within the text of its top-most
+/// ancestor, which is the document when the node is attached.
+pub fn node_range(node: &CstNode) -> TextRange {
+  // count the text before the node in one pass without allocating it
+  let mut counter = ByteCounter(0);
+  let mut current = node.clone();
+  while let Some(parent) = current.parent() {
+    for sibling in current.previous_siblings() {
+      let _ = write!(counter, "{}", sibling);
+    }
+    current = parent.into();
+  }
+  let start = counter.0;
+  let _ = write!(counter, "{}", node);
+  TextRange {
+    start,
+    end: counter.0,
+  }
+}
+
+/// Counts the bytes written to it.
+struct ByteCounter(usize);
+
+/// Gets the length of the node's text in bytes without allocating it.
+fn text_len(node: &CstNode) -> usize {
+  let mut counter = ByteCounter(0);
+  let _ = write!(counter, "{}", node);
+  counter.0
+}
+
+impl Write for ByteCounter {
+  fn write_str(&mut self, text: &str) -> std::fmt::Result {
+    self.0 += text.len();
+    Ok(())
+  }
+}
+
+/// Gets the text of the node's top-most ancestor.
+fn node_root_text(node: &CstNode) -> String {
+  match node.ancestors().last() {
+    Some(ancestor) => ancestor.to_string(),
+    None => node.to_string(),
+  }
+}
+
+/// Gets the UTF-16 range of the node within the text of its top-most
+/// ancestor.
+pub fn node_utf16_range(node: &CstNode) -> TextRange {
+  utf16_range(&node_root_text(node), node_range(node))
+}
+
+/// Gets the 1-based line and column where the node starts.
+pub fn node_start_line_column(node: &CstNode) -> LineColumn {
+  line_column(&node_root_text(node), node_range(node).start)
+}
+
+/// Gets the 1-based line and column where the node ends.
+pub fn node_end_line_column(node: &CstNode) -> LineColumn {
+  line_column(&node_root_text(node), node_range(node).end)
+}
+
+/// Converts a 1-based line and UTF-16 column to a byte offset in the text.
+///
+/// Returns `None` when the position is outside the text. The column may point
+/// one past the last character of the line, which is where its newline is.
+pub fn byte_offset_at_line_column(
+  text: &str,
+  line: usize,
+  column: usize,
+) -> Option<usize> {
+  if line == 0 || column == 0 {
+    return None;
+  }
+  let line_start = if line == 1 {
+    0
+  } else {
+    text.match_indices('\n').nth(line - 2)?.0 + 1
+  };
+  let line_text = text[line_start..].split('\n').next().unwrap();
+  let mut utf16_column = 1;
+  for (byte_index, c) in line_text.char_indices() {
+    if utf16_column >= column {
+      return (utf16_column == column).then_some(line_start + byte_index);
+    }
+    utf16_column += c.len_utf16();
+  }
+  (utf16_column == column).then_some(line_start + line_text.len())
+}
+
+/// Finds the deepest descendant of the node that contains the byte offset,
+/// which is relative to the start of the provided node.
+///
+/// When the offset is between two nodes, the node that starts at the offset
+/// is returned.
+pub fn node_at_offset(node: &CstNode, offset: usize) -> Option<CstNode> {
+  if offset >= text_len(node) {
+    return None;
+  }
+  let mut current = node.clone();
+  let mut start = 0;
+  'descend: loop {
+    for child in current.children() {
+      let end = start + text_len(&child);
+      if offset < end {
+        current = child;
+        continue 'descend;
+      }
+      start = end;
+    }
+    return Some(current);
+  }
+}
+
+/// Finds the outermost descendants of the node that overlap the byte range,
+/// which is relative to the start of the provided node.
+///
+/// Nodes entirely within the range are returned as a whole, while nodes that
+/// only partially overlap it are descended into. Leaf nodes that partially
+/// overlap the range are included.
+pub fn nodes_in_range(node: &CstNode, range: TextRange) -> Vec<CstNode> {
+  fn collect(
+    node: &CstNode,
+    node_start: usize,
+    range: TextRange,
+    nodes: &mut Vec<CstNode>,
+  ) {
+    let mut start = node_start;
+    for child in node.children() {
+      let end = start + text_len(&child);
+      if start < range.end && range.start < end {
+        let is_contained = range.start <= start && end <= range.end;
+        if is_contained || matches!(child, CstNode::Leaf(_)) {
+          nodes.push(child);
+        } else {
+          collect(&child, start, range, nodes);
+        }
+      }
+      start = end;
+    }
+  }
+
+  let mut nodes = Vec::new();
+  collect(node, 0, range, &mut nodes);
+  nodes
+}
+
+fn line_start_offset(text: &str, byte_offset: usize) -> usize {
+  text[..byte_offset].rfind('\n').map(|i| i + 1).unwrap_or(0)
+}
+
+fn clamp_to_char_boundary(text: &str, byte_offset: usize) -> usize {
+  let mut byte_offset = byte_offset.min(text.len());
+  while !text.is_char_boundary(byte_offset) {
+    byte_offset -= 1;
+  }
+  byte_offset
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use jsonc_parser::cst::CstRootNode;
+
+  use super::*;
+
+  fn parse_root(text: &str) -> CstRootNode {
+    CstRootNode::parse(text, &ParseOptions::default()).unwrap()
+  }
+
+  #[test]
+  fn measures_lines_and_columns_in_utf16() {
+    let text = "{\r\n  \"😀\": \"é\"\n}";
+    assert_eq!(line_column(text, 0), LineColumn { line: 1, column: 1 });
+    // after the emoji, which is 4 bytes and 2 UTF-16 code units
+    assert_eq!(line_column(text, 10), LineColumn { line: 2, column: 6 });
+    assert_eq!(byte_offset_at_line_column(text, 2, 6), Some(10));
+    // the end of a line is one past its last character
+    assert_eq!(byte_offset_at_line_column(text, 3, 2), Some(text.len()));
+    assert_eq!(byte_offset_at_line_column(text, 3, 3), None);
+    assert_eq!(byte_offset_at_line_column(text, 4, 1), None);
+    // within the emoji's surrogate pair
+    assert_eq!(byte_offset_at_line_column(text, 2, 5), None);
+    assert_eq!(
+      utf16_range(text, TextRange { start: 5, end: 16 }),
+      TextRange { start: 5, end: 13 }
+    );
+  }
+
+  #[test]
+  fn gets_node_ranges() {
+    let root = parse_root("{\n  \"é\": [1, 22]\n}");
+    let array = root.object_value().unwrap().array_value("é").unwrap();
+    let element = array.elements()[1].clone();
+    assert_eq!(node_range(&element), TextRange { start: 14, end: 16 });
+    assert_eq!(node_utf16_range(&element), TextRange { start: 13, end: 15 });
+    assert_eq!(
+      node_start_line_column(&element),
+      LineColumn {
+        line: 2,
+        column: 12
+      }
+    );
+    assert_eq!(
+      node_end_line_column(&array.into()),
+      LineColumn {
+        line: 2,
+        column: 15
+      }
+    );
+  }
+
+  #[test]
+  fn finds_nodes_at_offsets() {
+    let root: CstNode = parse_root("{ \"a\": [1, 22] }").into();
+    let text = |node: Option<CstNode>| node.map(|n| n.to_string());
+    assert_eq!(text(node_at_offset(&root, 2)), Some("\"a\"".to_string()));
+    assert_eq!(text(node_at_offset(&root, 12)), Some("22".to_string()));
+    // between two nodes, the one that starts at the offset
+    assert_eq!(text(node_at_offset(&root, 11)), Some("22".to_string()));
+    assert_eq!(text(node_at_offset(&root, 15)), Some("}".to_string()));
+    assert!(node_at_offset(&root, 16).is_none());
+  }
+
+  #[test]
+  fn finds_nodes_in_ranges() {
+    let root: CstNode = parse_root("{ \"a\": [1, 22], \"b\": 3 }").into();
+    let texts = |range: TextRange| {
+      nodes_in_range(&root, range)
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(texts(TextRange { start: 7, end: 15 }), vec!["[1, 22]", ","]);
+    // partially overlapped containers are descended into
+    assert_eq!(
+      texts(TextRange { start: 10, end: 20 }),
+      vec![" ", "22", "]", ",", " ", "\"b\"", ":"]
+    );
+    assert_eq!(
+      texts(TextRange { start: 13, end: 13 }),
+      Vec::<String>::new()
+    );
+  }
+
+  #[test]
+  fn describes_parse_errors() {
+    let text = "{\n  \"é\": 'x\n}";
+    let err = CstRootNode::parse(text, &ParseOptions::default()).unwrap_err();
+    assert_eq!(
+      parse_error_message(&err, text),
+      "Unterminated string literal on line 2 column 8"
+    );
+  }
+
+  #[test]
+  fn renders_code_frames_for_ranges_after_carriage_returns() {
+    let text = "{1\n\r";
+    assert_eq!(
+      code_frame(text, TextRange { start: 4, end: 4 }),
+      "  1 | {1\n> 2 |\n    | ^"
+    );
+  }
+}
@@ -0,0 +1,108 @@
+use jsonc_parser::cst::CstInputValue;
+use jsonc_parser::cst::ObjectPropName;
+
+/// Changes the property name, keeping the quote style of the existing name.
+///
+/// A name without quotes stays without quotes when the new name is a valid
+/// identifier. Otherwise it's replaced with a double quoted string.
+///
+/// Returns the name node, which is a new node when the name was replaced.
+pub fn set_name(name: &ObjectPropName, new_name: &str) -> ObjectPropName {
+  match name {
+    ObjectPropName::String(lit) => {
+      let is_single_quoted = lit.raw_value().starts_with('\'');
+      lit.set_raw_value(if is_single_quoted {
+        single_quoted(new_name)
+      } else {
+        double_quoted(new_name)
+      });
+      name.clone()
+    }
+    ObjectPropName::Word(lit) if is_identifier(new_name) => {
+      lit.set_raw_value(new_name.to_string());
+      name.clone()
+    }
+    ObjectPropName::Word(lit) => {
+      let node = lit
+        .clone()
+        .replace_with(CstInputValue::String(new_name.to_string()))
+        .expect("property names should have a parent");
+      ObjectPropName::String(node.as_string_lit().unwrap())
+    }
+  }
+}
+
+/// Quotes the value as a double quoted string.
+pub(crate) fn double_quoted(value: &str) -> String {
+  serde_json::to_string(value).unwrap()
+}
+
+/// Quotes the value as a single quoted string.
+pub(crate) fn single_quoted(value: &str) -> String {
+  let mut text = String::with_capacity(value.len() + 2);
+  text.push('\'');
+  for c in value.chars() {
+    match c {
+      '\'' => text.push_str("\\'"),
+      '"' => text.push('"'),
+      // reuse the JSON escapes for backslashes and control characters
+      _ => {
+        let escaped = serde_json::to_string(&c.to_string()).unwrap();
+        text.push_str(&escaped[1..escaped.len() - 1]);
+      }
+    }
+  }
+  text.push('\'');
+  text
+}
+
+/// Gets if the name can be written without quotes.
+fn is_identifier(value: &str) -> bool {
+  let mut chars = value.chars();
+  let Some(first) = chars.next() else {
+    return false;
+  };
+  (matches!(first, '_' | '$') || first.is_alphabetic())
+    && chars.all(|c| matches!(c, '_' | '$') || c.is_alphanumeric())
+    && !matches!(value, "true" | "false" | "null")
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use jsonc_parser::cst::CstRootNode;
+
+  use super::*;
+
+  fn rename(text: &str, new_name: &str) -> String {
+    let root = CstRootNode::parse(text, &ParseOptions::default()).unwrap();
+    let prop = root.object_value().unwrap().properties()[0].clone();
+    set_name(&prop.name().unwrap(), new_name);
+    root.to_string()
+  }
+
+  #[test]
+  fn keeps_the_quote_style() {
+    assert_eq!(
+      rename("{ \"a\": 1 }", "it's \"b\""),
+      "{ \"it's \\\"b\\\"\": 1 }"
+    );
+    assert_eq!(rename("{ 'a': 1 }", "it's \"b\""), "{ 'it\\'s \"b\"': 1 }");
+    assert_eq!(rename("{ a: 1 }", "b_$1"), "{ b_$1: 1 }");
+  }
+
+  #[test]
+  fn quotes_names_that_are_not_identifiers() {
+    assert_eq!(rename("{ a: 1 }", "a-b"), "{ \"a-b\": 1 }");
+    assert_eq!(rename("{ a: 1 }", "1a"), "{ \"1a\": 1 }");
+    assert_eq!(rename("{ a: 1 }", "null"), "{ \"null\": 1 }");
+    assert_eq!(rename("{ a: 1 }", ""), "{ \"\": 1 }");
+  }
+
+  #[test]
+  fn handles_unicode_names() {
+    assert_eq!(rename("{ a: 1 }", "émoji"), "{ émoji: 1 }");
+    assert_eq!(rename("{ a: 1 }", "😀"), "{ \"😀\": 1 }");
+    assert_eq!(rename("{ 'a': 1 }", "日本\n"), "{ '日本\\n': 1 }");
+  }
+}
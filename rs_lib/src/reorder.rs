@@ -0,0 +1,253 @@
+use std::fmt;
+
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstObject;
+use jsonc_parser::cst::CstObjectProp;
+
+use crate::values;
+use crate::values::DetachedNode;
+
+/// Error that occurs when properties or elements can't be reordered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReorderError {
+  /// The index is out of bounds.
+  InvalidIndex { index: usize, len: usize },
+  /// The node isn't a property or array element.
+  NotMovable,
+  /// The target object is within the property being moved.
+  TargetWithinProperty,
+}
+
+impl fmt::Display for ReorderError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ReorderError::InvalidIndex { index, len } => write!(
+        f,
+        "Index {} is out of bounds for a length of {}",
+        index, len
+      ),
+      ReorderError::NotMovable => {
+        write!(f, "Expected an object property or array element")
+      }
+      ReorderError::TargetWithinProperty => {
+        write!(f, "Cannot move a property into an object within its value")
+      }
+    }
+  }
+}
+
+/// A planned reordering of the properties or elements of a container.
+///
+/// Moved nodes are removed and inserted again along with their comments.
+/// Only the nodes that aren't part of the longest subsequence already in the
+/// right order are moved, which keeps the formatting around the others.
+pub(crate) struct Plan {
+  /// Indexes of the nodes in their new order.
+  order: Vec<usize>,
+  /// Whether the node at each index stays in place.
+  keep: Vec<bool>,
+}
+
+impl Plan {
+  /// Plans the reordering of the nodes, where `order` contains the indexes
+  /// of the nodes in their new order.
+  pub(crate) fn new(order: Vec<usize>) -> Self {
+    let mut rank = vec![0; order.len()];
+    for (position, index) in order.iter().enumerate() {
+      rank[*index] = position;
+    }
+    let keep = kept_nodes(&rank);
+    Self { order, keep }
+  }
+}
+
+/// Reorders the object's properties according to the plan.
+pub(crate) fn execute_properties(obj: &CstObject, plan: Plan) {
+  let props = obj.properties();
+  let mut moved = Vec::new();
+  for (position, index) in plan.order.iter().enumerate() {
+    if !plan.keep[*index] {
+      let prop = props[*index].clone().into();
+      moved.push((position, DetachedNode::remove(&prop)));
+    }
+  }
+  // insert in order so every property before the index is present
+  for (position, removed) in moved {
+    removed.insert_property(obj, position);
+  }
+}
+
+/// Moves the property to the index within its object, along with its
+/// comments.
+pub fn move_property(
+  prop: &CstObjectProp,
+  index: usize,
+) -> Result<CstObjectProp, ReorderError> {
+  let Some(obj) = prop.parent().and_then(|p| p.as_object()) else {
+    return Err(ReorderError::NotMovable);
+  };
+  let len = obj.properties().len();
+  if index >= len {
+    return Err(ReorderError::InvalidIndex { index, len });
+  }
+  if index != prop.property_index() {
+    DetachedNode::remove(&prop.clone().into()).insert_property(&obj, index);
+  }
+  Ok(prop.clone())
+}
+
+/// Moves the property to the index of another object, along with its
+/// comments.
+pub fn move_property_to_object(
+  prop: &CstObjectProp,
+  target: &CstObject,
+  index: usize,
+) -> Result<CstObjectProp, ReorderError> {
+  if prop
+    .parent()
+    .and_then(|p| p.as_object())
+    .is_some_and(|obj| obj.ptr_eq(target))
+  {
+    return move_property(prop, index);
+  }
+  let target_node: CstNode = target.clone().into();
+  if target_node
+    .ancestors()
+    .any(|a| a.as_object_prop().is_some_and(|a| a.ptr_eq(prop)))
+  {
+    return Err(ReorderError::TargetWithinProperty);
+  }
+  let len = target.properties().len();
+  if index > len {
+    return Err(ReorderError::InvalidIndex { index, len });
+  }
+  Ok(DetachedNode::remove(&prop.clone().into()).insert_property(target, index))
+}
+
+/// Moves the array element to the index within its array, along with its
+/// comments.
+pub fn move_element(
+  element: &CstNode,
+  index: usize,
+) -> Result<CstNode, ReorderError> {
+  let (Some(array), Some(from)) = (
+    element.parent().and_then(|p| p.as_array()),
+    values::element_index(element),
+  ) else {
+    return Err(ReorderError::NotMovable);
+  };
+  let len = array.elements().len();
+  if index >= len {
+    return Err(ReorderError::InvalidIndex { index, len });
+  }
+  if index != from {
+    DetachedNode::remove(element).insert_element(&array, index);
+  }
+  Ok(element.clone())
+}
+
+/// Finds the nodes that can stay in place, which is the longest subsequence
+/// of nodes already in order.
+fn kept_nodes(rank: &[usize]) -> Vec<bool> {
+  let len = rank.len();
+  let mut best = vec![0; len];
+  let mut previous = vec![None; len];
+  for i in 0..len {
+    best[i] = 1;
+    for j in 0..i {
+      if rank[j] < rank[i] && best[j] + 1 > best[i] {
+        best[i] = best[j] + 1;
+        previous[i] = Some(j);
+      }
+    }
+  }
+  let mut keep = vec![false; len];
+  let mut current = (0..len).max_by_key(|i| best[*i]);
+  while let Some(i) = current {
+    keep[i] = true;
+    current = previous[i];
+  }
+  keep
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use jsonc_parser::cst::CstRootNode;
+
+  use super::*;
+
+  fn parse_root(text: &str) -> CstRootNode {
+    CstRootNode::parse(text, &ParseOptions::default()).unwrap()
+  }
+
+  #[test]
+  fn moves_properties_with_comments() {
+    let root = parse_root(concat!(
+      "{\n  // a doc\n  \"a\": {\n    \"x\": 1 // x\n  }, // after a\n",
+      "  /* b */ \"b\": 2,\n  \"c\": 3\n}",
+    ));
+    let obj = root.object_value().unwrap();
+    let a = obj.get("a").unwrap();
+    let moved = move_property(&a, 2).unwrap();
+    assert!(moved.ptr_eq(&a));
+    assert_eq!(
+      root.to_string(),
+      concat!(
+        "{\n  /* b */ \"b\": 2,\n  \"c\": 3,\n  // a doc\n  \"a\": {\n",
+        "    \"x\": 1 // x\n  } // after a\n}",
+      )
+    );
+    move_property(&obj.get("b").unwrap(), 2).unwrap();
+    assert_eq!(
+      root.to_string(),
+      concat!(
+        "{\n  \"c\": 3,\n  // a doc\n  \"a\": {\n    \"x\": 1 // x\n",
+        "  }, // after a\n  /* b */ \"b\": 2\n}",
+      )
+    );
+  }
+
+  #[test]
+  fn moves_properties_to_other_documents() {
+    let root = parse_root(concat!(
+      "{\n  \"c\": 3,\n  // a doc\n  \"a\": {\n    \"x\": 1 // x\n",
+      "  }, // after a\n  \"b\": 2\n}",
+    ));
+    let obj = root.object_value().unwrap();
+    let target = parse_root("{\r\n  \"t\": {\r\n    \"u\": 1\r\n  }\r\n}");
+    let t = target.object_value().unwrap().object_value("t").unwrap();
+    move_property_to_object(&obj.get("a").unwrap(), &t, 0).unwrap();
+    assert_eq!(
+      target.to_string(),
+      concat!(
+        "{\r\n  \"t\": {\r\n    // a doc\r\n    \"a\": {\r\n",
+        "      \"x\": 1 // x\r\n    }, // after a\r\n    \"u\": 1\r\n  }\r\n}",
+      )
+    );
+    assert_eq!(root.to_string(), "{\n  \"c\": 3,\n  \"b\": 2\n}");
+
+    let a = t.get("a").unwrap();
+    let x = a.object_value().unwrap();
+    assert_eq!(
+      move_property_to_object(&a, &x, 0).unwrap_err(),
+      ReorderError::TargetWithinProperty
+    );
+  }
+
+  #[test]
+  fn moves_elements_with_comments() {
+    let root = parse_root("[1, /* one */ 2, 3]");
+    let elements = root.array_value().unwrap().elements();
+    move_element(&elements[1], 0).unwrap();
+    move_element(&elements[2], 1).unwrap();
+    assert_eq!(root.to_string(), "[/* one */ 2, 3, 1]");
+
+    let root = parse_root("[\n  1,\n  2, // two\n  3\n]");
+    let elements = root.array_value().unwrap().elements();
+    move_element(&elements[1], 2).unwrap();
+    assert_eq!(root.to_string(), "[\n  1,\n  3,\n  2 // two\n]");
+    move_element(&elements[1], 0).unwrap();
+    assert_eq!(root.to_string(), "[\n  2, // two\n  1,\n  3\n]");
+  }
+}
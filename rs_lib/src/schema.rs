@@ -0,0 +1,815 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstLeafNode;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstObject;
+use jsonc_parser::cst::CstRootNode;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::patch::json_equal;
+use crate::pointer;
+use crate::position;
+use crate::position::LineColumn;
+use crate::position::TextRange;
+use crate::values;
+
+/// Maximum number of `$ref`s followed without moving into a child value,
+/// which stops references to themselves from recursing forever.
+const MAX_REF_DEPTH: usize = 64;
+
+/// A compiled pattern, which tests if a text matches it.
+pub type Pattern = Box<dyn Fn(&str) -> bool>;
+
+/// Compiles a pattern, which is an ECMA-262 regular expression. Returns the
+/// error message when the pattern is invalid.
+pub type PatternCompiler<'a> = dyn Fn(&str) -> Result<Pattern, String> + 'a;
+
+/// A value in the document that doesn't match the schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationError {
+  /// Schema keyword that failed (ex. `type` or `required`).
+  pub keyword: String,
+  pub message: String,
+  /// JSON Pointer of the value that failed.
+  pub pointer: String,
+  /// JSON Pointer of the keyword in the schema, which is within the
+  /// definition for keywords reached through a `$ref`.
+  pub schema_pointer: String,
+  /// Name of the property that's missing or not allowed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub key: Option<String>,
+  /// Byte range of the value, or of the whole property when the property
+  /// isn't allowed.
+  pub range: TextRange,
+  pub utf16_range: TextRange,
+  pub start: LineColumn,
+  pub end: LineColumn,
+}
+
+/// Error that occurs when the schema itself is invalid or uses something
+/// that isn't supported, such as a reference to another document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+  /// JSON Pointer of the invalid part of the schema.
+  pub schema_pointer: String,
+  pub message: String,
+}
+
+impl fmt::Display for SchemaError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Invalid schema at '{}': {}",
+      self.schema_pointer, self.message
+    )
+  }
+}
+
+impl SchemaError {
+  fn new(schema_pointer: &str, message: impl Into<String>) -> Self {
+    Self {
+      schema_pointer: schema_pointer.to_string(),
+      message: message.into(),
+    }
+  }
+}
+
+/// Validates the document's value against a JSON Schema (draft 2020-12).
+///
+/// The supported keywords are `type`, `enum`, `pattern`, `properties`,
+/// `patternProperties`, `additionalProperties`, `required`, `items`,
+/// `allOf`, `anyOf`, `oneOf` and `$ref` to a location in the same schema
+/// (ex. `#/$defs/name`). Other keywords are ignored. A document without a
+/// value has nothing to validate. Each pattern is compiled once.
+pub fn validate(
+  root: &CstRootNode,
+  schema: &Value,
+  compile_pattern: &PatternCompiler,
+) -> Result<Vec<ValidationError>, SchemaError> {
+  let Some(value) = root.value() else {
+    return Ok(Vec::new());
+  };
+  let mut validator = Validator {
+    root_schema: schema,
+    compile_pattern,
+    patterns: HashMap::new(),
+    text: root.to_string(),
+    errors: Vec::new(),
+  };
+  validator.validate_node(&value, &mut Vec::new(), schema, "", 0)?;
+  Ok(validator.errors)
+}
+
+struct Validator<'a> {
+  root_schema: &'a Value,
+  compile_pattern: &'a PatternCompiler<'a>,
+  /// Compiled patterns, or why they're invalid, by their source.
+  patterns: HashMap<String, Result<Pattern, String>>,
+  text: String,
+  errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+  fn validate_node(
+    &mut self,
+    node: &CstNode,
+    tokens: &mut Vec<String>,
+    schema: &'a Value,
+    schema_pointer: &str,
+    ref_depth: usize,
+  ) -> Result<(), SchemaError> {
+    let schema = match schema {
+      Value::Bool(true) => return Ok(()),
+      Value::Bool(false) => {
+        let keyword = schema_pointer.rsplit('/').next().unwrap_or_default();
+        self.add_error(
+          node,
+          tokens,
+          if keyword.is_empty() { "false" } else { keyword },
+          schema_pointer.to_string(),
+          "No value is allowed here".to_string(),
+          None,
+        );
+        return Ok(());
+      }
+      Value::Object(schema) => schema,
+      _ => {
+        return Err(SchemaError::new(
+          schema_pointer,
+          "expected an object or boolean",
+        ));
+      }
+    };
+    let keyword_pointer =
+      |keyword: &str| format!("{}/{}", schema_pointer, keyword);
+
+    if let Some(reference) = schema.get("$ref") {
+      let ref_pointer = keyword_pointer("$ref");
+      if ref_depth == MAX_REF_DEPTH {
+        return Err(SchemaError::new(
+          &ref_pointer,
+          "references don't reach a value to validate",
+        ));
+      }
+      let (target, target_pointer) =
+        self.resolve_ref(reference, &ref_pointer)?;
+      self.validate_node(
+        node,
+        tokens,
+        target,
+        &target_pointer,
+        ref_depth + 1,
+      )?;
+    }
+
+    let kind = value_kind(node);
+    if let Some(types) = schema.get("type") {
+      let types_pointer = keyword_pointer("type");
+      let types = match types {
+        Value::String(name) => vec![name.as_str()],
+        Value::Array(names) => names
+          .iter()
+          .map(|name| name.as_str())
+          .collect::<Option<Vec<_>>>()
+          .ok_or_else(|| {
+            SchemaError::new(&types_pointer, "expected an array of strings")
+          })?,
+        _ => {
+          return Err(SchemaError::new(
+            &types_pointer,
+            "expected a string or array",
+          ));
+        }
+      };
+      let mut is_match = false;
+      for name in &types {
+        is_match |= match *name {
+          "integer" => kind == "number" && is_integer(node),
+          "object" | "array" | "string" | "number" | "boolean" | "null" => {
+            kind == *name
+          }
+          _ => {
+            return Err(SchemaError::new(
+              &types_pointer,
+              format!("unknown type '{}'", name),
+            ));
+          }
+        };
+      }
+      if !is_match {
+        let expected = types
+          .iter()
+          .map(|name| with_article(name))
+          .collect::<Vec<_>>()
+          .join(" or ");
+        self.add_error(
+          node,
+          tokens,
+          "type",
+          types_pointer,
+          format!("Expected {}, but found {}", expected, with_article(kind)),
+          None,
+        );
+      }
+    }
+
+    if let Some(options) = schema.get("enum") {
+      let enum_pointer = keyword_pointer("enum");
+      let Value::Array(options) = options else {
+        return Err(SchemaError::new(&enum_pointer, "expected an array"));
+      };
+      let value = values::to_serde_value(node);
+      let is_match = value
+        .is_some_and(|value| options.iter().any(|o| json_equal(o, &value)));
+      if !is_match {
+        let expected = options
+          .iter()
+          .map(|option| option.to_string())
+          .collect::<Vec<_>>()
+          .join(", ");
+        self.add_error(
+          node,
+          tokens,
+          "enum",
+          enum_pointer,
+          format!("Expected one of {}", expected),
+          None,
+        );
+      }
+    }
+
+    if let Some(pattern) = schema.get("pattern") {
+      let pattern_pointer = keyword_pointer("pattern");
+      let pattern = pattern.as_str().ok_or_else(|| {
+        SchemaError::new(&pattern_pointer, "expected a string")
+      })?;
+      if let Some(text) = string_value(node)
+        && !self.matches(pattern, &text, &pattern_pointer)?
+      {
+        self.add_error(
+          node,
+          tokens,
+          "pattern",
+          pattern_pointer,
+          format!("Expected the string to match the pattern '{}'", pattern),
+          None,
+        );
+      }
+    }
+
+    if let Some(subschemas) = schema.get("allOf") {
+      let all_of_pointer = keyword_pointer("allOf");
+      for (i, subschema) in subschemas_of(subschemas, &all_of_pointer)? {
+        let subschema_pointer = format!("{}/{}", all_of_pointer, i);
+        self.validate_node(
+          node,
+          tokens,
+          subschema,
+          &subschema_pointer,
+          ref_depth,
+        )?;
+      }
+    }
+    for keyword in ["anyOf", "oneOf"] {
+      let Some(subschemas) = schema.get(keyword) else {
+        continue;
+      };
+      let combinator_pointer = keyword_pointer(keyword);
+      let mut match_count = 0;
+      for (i, subschema) in subschemas_of(subschemas, &combinator_pointer)? {
+        let subschema_pointer = format!("{}/{}", combinator_pointer, i);
+        if self.is_valid(
+          node,
+          tokens,
+          subschema,
+          &subschema_pointer,
+          ref_depth,
+        )? {
+          match_count += 1;
+        }
+      }
+      let message = match (keyword, match_count) {
+        ("anyOf", 0) => {
+          Some("Expected the value to match at least one schema".to_string())
+        }
+        ("oneOf", 0) => Some(
+          "Expected the value to match exactly one schema, but it matched none"
+            .to_string(),
+        ),
+        ("oneOf", count) if count > 1 => Some(format!(
+          "Expected the value to match exactly one schema, but it matched {}",
+          count
+        )),
+        _ => None,
+      };
+      if let Some(message) = message {
+        self.add_error(
+          node,
+          tokens,
+          keyword,
+          combinator_pointer,
+          message,
+          None,
+        );
+      }
+    }
+
+    match node {
+      CstNode::Container(CstContainerNode::Object(object)) => {
+        self.validate_object(object, tokens, schema, schema_pointer)?;
+      }
+      CstNode::Container(CstContainerNode::Array(array)) => {
+        if let Some(items) = schema.get("items") {
+          let items_pointer = keyword_pointer("items");
+          for (i, element) in array.elements().iter().enumerate() {
+            tokens.push(i.to_string());
+            self.validate_node(element, tokens, items, &items_pointer, 0)?;
+            tokens.pop();
+          }
+        }
+      }
+      _ => {}
+    }
+    Ok(())
+  }
+
+  fn validate_object(
+    &mut self,
+    object: &CstObject,
+    tokens: &mut Vec<String>,
+    schema: &'a serde_json::Map<String, Value>,
+    schema_pointer: &str,
+  ) -> Result<(), SchemaError> {
+    let properties = match schema.get("properties") {
+      Some(Value::Object(properties)) => Some(properties),
+      Some(_) => {
+        return Err(SchemaError::new(
+          &format!("{}/properties", schema_pointer),
+          "expected an object",
+        ));
+      }
+      None => None,
+    };
+    let pattern_properties = match schema.get("patternProperties") {
+      Some(Value::Object(properties)) => Some(properties),
+      Some(_) => {
+        return Err(SchemaError::new(
+          &format!("{}/patternProperties", schema_pointer),
+          "expected an object",
+        ));
+      }
+      None => None,
+    };
+    let additional = schema.get("additionalProperties");
+
+    for prop in object.properties() {
+      let (Some(name), Some(value)) = (
+        prop.name().and_then(|name| name.decoded_value().ok()),
+        prop.value(),
+      ) else {
+        continue;
+      };
+      let prop: CstNode = prop.into();
+      let mut applied = Vec::new();
+      if let Some(subschema) = properties.and_then(|p| p.get(&name)) {
+        let pointer = format!(
+          "{}{}",
+          schema_pointer,
+          pointer::format(&["properties", &name])
+        );
+        applied.push(("properties", subschema, pointer));
+      }
+      for (pattern, subschema) in pattern_properties.into_iter().flatten() {
+        let pointer = format!(
+          "{}{}",
+          schema_pointer,
+          pointer::format(&["patternProperties", pattern])
+        );
+        if self.matches(pattern, &name, &pointer)? {
+          applied.push(("patternProperties", subschema, pointer));
+        }
+      }
+      if applied.is_empty()
+        && let Some(subschema) = additional
+      {
+        let pointer = format!("{}/additionalProperties", schema_pointer);
+        applied.push(("additionalProperties", subschema, pointer));
+      }
+
+      tokens.push(name.clone());
+      for (keyword, subschema, pointer) in applied {
+        if subschema == &Value::Bool(false) {
+          self.add_error(
+            &prop,
+            tokens,
+            keyword,
+            pointer,
+            format!("Property '{}' is not allowed", name),
+            Some(&name),
+          );
+        } else {
+          self.validate_node(&value, tokens, subschema, &pointer, 0)?;
+        }
+      }
+      tokens.pop();
+    }
+
+    if let Some(required) = schema.get("required") {
+      let required_pointer = format!("{}/required", schema_pointer);
+      let names = required
+        .as_array()
+        .and_then(|names| {
+          names
+            .iter()
+            .map(|name| name.as_str())
+            .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| {
+          SchemaError::new(&required_pointer, "expected an array of strings")
+        })?;
+      let object_node: CstNode = object.clone().into();
+      for name in names {
+        if object.get(name).is_none() {
+          self.add_error(
+            &object_node,
+            tokens,
+            "required",
+            required_pointer.clone(),
+            format!("Missing required property '{}'", name),
+            Some(name),
+          );
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Gets if the value matches the schema without keeping its errors.
+  fn is_valid(
+    &mut self,
+    node: &CstNode,
+    tokens: &mut Vec<String>,
+    schema: &'a Value,
+    schema_pointer: &str,
+    ref_depth: usize,
+  ) -> Result<bool, SchemaError> {
+    let error_count = self.errors.len();
+    self.validate_node(node, tokens, schema, schema_pointer, ref_depth)?;
+    let is_valid = self.errors.len() == error_count;
+    self.errors.truncate(error_count);
+    Ok(is_valid)
+  }
+
+  /// Resolves a `$ref` to a location in the schema, returning the schema
+  /// there and its pointer.
+  fn resolve_ref(
+    &self,
+    reference: &Value,
+    ref_pointer: &str,
+  ) -> Result<(&'a Value, String), SchemaError> {
+    let reference = reference
+      .as_str()
+      .ok_or_else(|| SchemaError::new(ref_pointer, "expected a string"))?;
+    let Some(fragment) = reference.strip_prefix('#') else {
+      return Err(SchemaError::new(
+        ref_pointer,
+        format!(
+          "only references within the schema are supported, but found '{}'",
+          reference
+        ),
+      ));
+    };
+    let target_pointer = percent_decode(fragment).ok_or_else(|| {
+      SchemaError::new(
+        ref_pointer,
+        format!("invalid reference '{}'", reference),
+      )
+    })?;
+    let tokens = pointer::parse(&target_pointer)
+      .map_err(|err| SchemaError::new(ref_pointer, err.to_string()))?;
+    let mut target = self.root_schema;
+    for token in &tokens {
+      target = match target {
+        Value::Object(object) => object.get(token),
+        Value::Array(array) => {
+          pointer::parse_array_index(token).and_then(|i| array.get(i))
+        }
+        _ => None,
+      }
+      .ok_or_else(|| {
+        SchemaError::new(
+          ref_pointer,
+          format!("'{}' doesn't exist in the schema", reference),
+        )
+      })?;
+    }
+    Ok((target, target_pointer))
+  }
+
+  fn matches(
+    &mut self,
+    pattern: &str,
+    text: &str,
+    pattern_pointer: &str,
+  ) -> Result<bool, SchemaError> {
+    let compile_pattern = self.compile_pattern;
+    let compiled = self
+      .patterns
+      .entry(pattern.to_string())
+      .or_insert_with(|| compile_pattern(pattern));
+    match compiled {
+      Ok(compiled) => Ok(compiled(text)),
+      Err(message) => Err(SchemaError::new(
+        pattern_pointer,
+        format!("invalid pattern '{}': {}", pattern, message),
+      )),
+    }
+  }
+
+  fn add_error(
+    &mut self,
+    node: &CstNode,
+    tokens: &[String],
+    keyword: &str,
+    schema_pointer: String,
+    message: String,
+    key: Option<&str>,
+  ) {
+    let range = position::node_range(node);
+    self.errors.push(ValidationError {
+      keyword: keyword.to_string(),
+      message,
+      pointer: pointer::format(tokens),
+      schema_pointer,
+      key: key.map(|key| key.to_string()),
+      range,
+      utf16_range: position::utf16_range(&self.text, range),
+      start: position::line_column(&self.text, range.start),
+      end: position::line_column(&self.text, range.end),
+    });
+  }
+}
+
+/// Gets the JSON Schema type of a value node. Words are strings, like when
+/// converting to a value.
+fn value_kind(node: &CstNode) -> &'static str {
+  match node {
+    CstNode::Container(CstContainerNode::Object(_)) => "object",
+    CstNode::Container(CstContainerNode::Array(_)) => "array",
+    CstNode::Leaf(CstLeafNode::StringLit(_) | CstLeafNode::WordLit(_)) => {
+      "string"
+    }
+    CstNode::Leaf(CstLeafNode::NumberLit(_)) => "number",
+    CstNode::Leaf(CstLeafNode::BooleanLit(_)) => "boolean",
+    CstNode::Leaf(CstLeafNode::NullKeyword(_)) => "null",
+    _ => "value",
+  }
+}
+
+fn is_integer(node: &CstNode) -> bool {
+  match values::to_serde_value(node) {
+    Some(Value::Number(number)) => {
+      number.is_i64()
+        || number.is_u64()
+        || number.as_f64().is_some_and(|n| n.fract() == 0.0)
+    }
+    _ => false,
+  }
+}
+
+fn string_value(node: &CstNode) -> Option<String> {
+  match node {
+    CstNode::Leaf(CstLeafNode::StringLit(lit)) => lit.decoded_value().ok(),
+    CstNode::Leaf(CstLeafNode::WordLit(word)) => Some(word.to_string()),
+    _ => None,
+  }
+}
+
+fn with_article(kind: &str) -> String {
+  match kind {
+    "null" => kind.to_string(),
+    "object" | "array" | "integer" => format!("an {}", kind),
+    _ => format!("a {}", kind),
+  }
+}
+
+fn subschemas_of<'a>(
+  subschemas: &'a Value,
+  schema_pointer: &str,
+) -> Result<impl Iterator<Item = (usize, &'a Value)>, SchemaError> {
+  match subschemas {
+    Value::Array(subschemas) if !subschemas.is_empty() => {
+      Ok(subschemas.iter().enumerate())
+    }
+    _ => Err(SchemaError::new(
+      schema_pointer,
+      "expected a non-empty array",
+    )),
+  }
+}
+
+/// Decodes the percent-encoded bytes of a URI fragment.
+fn percent_decode(text: &str) -> Option<String> {
+  let mut bytes = Vec::with_capacity(text.len());
+  let mut rest = text.as_bytes();
+  while let Some((&byte, after)) = rest.split_first() {
+    if byte == b'%' {
+      let hex = std::str::from_utf8(after.get(..2)?).ok()?;
+      bytes.push(u8::from_str_radix(hex, 16).ok()?);
+      rest = &after[2..];
+    } else {
+      bytes.push(byte);
+      rest = after;
+    }
+  }
+  String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use serde_json::json;
+
+  use super::*;
+
+  fn parse_root(text: &str) -> CstRootNode {
+    CstRootNode::parse(text, &ParseOptions::default()).unwrap()
+  }
+
+  /// Supports the `^` anchor and otherwise tests if the text contains the
+  /// pattern, which is enough for these tests.
+  fn compile_pattern(pattern: &str) -> Result<Pattern, String> {
+    let pattern = pattern.to_string();
+    match pattern.strip_prefix('^') {
+      Some(prefix) => {
+        let prefix = prefix.to_string();
+        Ok(Box::new(move |text| text.starts_with(&prefix)))
+      }
+      None if pattern.contains('(') => Err("unterminated group".to_string()),
+      None => Ok(Box::new(move |text| text.contains(&pattern))),
+    }
+  }
+
+  fn messages(text: &str, schema: &Value) -> Vec<(String, String)> {
+    validate(&parse_root(text), schema, &compile_pattern)
+      .unwrap()
+      .into_iter()
+      .map(|err| (err.pointer, err.message))
+      .collect()
+  }
+
+  #[test]
+  fn reports_errors_at_values() {
+    let text = "{\n  // comment\n  \"name\": 1,\n  \"extra\": true\n}";
+    let schema = json!({
+      "type": "object",
+      "properties": { "name": { "type": ["string", "null"] } },
+      "required": ["name", "version"],
+      "additionalProperties": false,
+    });
+    let errors =
+      validate(&parse_root(text), &schema, &compile_pattern).unwrap();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].keyword, "type");
+    assert_eq!(
+      errors[0].message,
+      "Expected a string or null, but found a number"
+    );
+    assert_eq!(errors[0].pointer, "/name");
+    assert_eq!(errors[0].schema_pointer, "/properties/name/type");
+    assert_eq!(errors[0].range, TextRange { start: 25, end: 26 });
+    assert_eq!(
+      errors[0].start,
+      LineColumn {
+        line: 3,
+        column: 11
+      }
+    );
+    assert_eq!(errors[1].keyword, "additionalProperties");
+    assert_eq!(errors[1].pointer, "/extra");
+    assert_eq!(errors[1].key.as_deref(), Some("extra"));
+    assert_eq!(
+      &text[errors[1].range.start..errors[1].range.end],
+      "\"extra\": true"
+    );
+    assert_eq!(errors[2].keyword, "required");
+    assert_eq!(errors[2].message, "Missing required property 'version'");
+    assert_eq!(errors[2].pointer, "");
+    assert_eq!(
+      errors[2].range,
+      TextRange {
+        start: 0,
+        end: text.len()
+      }
+    );
+  }
+
+  #[test]
+  fn follows_refs_and_combinators() {
+    let schema = json!({
+      "$defs": {
+        "task": {
+          "anyOf": [
+            { "type": "string", "pattern": "^deno " },
+            { "type": "object", "required": ["command"] },
+          ],
+        },
+        "a/b": { "enum": [1, "two"] },
+      },
+      "properties": {
+        "tasks": {
+          "patternProperties": { "^build": { "$ref": "#/$defs/task" } },
+          "additionalProperties": { "type": "string" },
+        },
+        "level": { "$ref": "#/$defs/a~1b" },
+        "id": { "oneOf": [{ "type": "integer" }, { "type": "number" }] },
+        "list": { "items": { "type": "boolean" } },
+      },
+    });
+    assert_eq!(
+      messages(
+        r#"{ "tasks": { "build": "deno run", "other": "x" }, "level": 1.0, "list": [true] }"#,
+        &schema,
+      ),
+      Vec::new()
+    );
+    assert_eq!(
+      messages(
+        r#"{ "tasks": { "build": "npm run", "other": 1 }, "level": 3, "id": 1, "list": [false, 0] }"#,
+        &schema,
+      ),
+      vec![
+        (
+          "/tasks/build".to_string(),
+          "Expected the value to match at least one schema".to_string()
+        ),
+        (
+          "/tasks/other".to_string(),
+          "Expected a string, but found a number".to_string()
+        ),
+        (
+          "/level".to_string(),
+          "Expected one of 1, \"two\"".to_string()
+        ),
+        (
+          "/id".to_string(),
+          "Expected the value to match exactly one schema, but it matched 2"
+            .to_string()
+        ),
+        (
+          "/list/1".to_string(),
+          "Expected a boolean, but found a number".to_string()
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn rejects_invalid_schemas() {
+    let root = parse_root("{ \"a\": \"b\" }");
+    let error = |schema: Value| {
+      validate(&root, &schema, &compile_pattern)
+        .unwrap_err()
+        .to_string()
+    };
+    assert_eq!(
+      error(json!({ "$ref": "other.json#/a" })),
+      "Invalid schema at '/$ref': only references within the schema are supported, but found 'other.json#/a'"
+    );
+    assert_eq!(
+      error(json!({ "properties": { "a": { "$ref": "#/$defs/x" } } })),
+      "Invalid schema at '/properties/a/$ref': '#/$defs/x' doesn't exist in the schema"
+    );
+    assert_eq!(
+      error(
+        json!({ "$defs": { "a": { "$ref": "#/$defs/a" } }, "$ref": "#/$defs/a" })
+      ),
+      "Invalid schema at '/$defs/a/$ref': references don't reach a value to validate"
+    );
+    assert_eq!(
+      validate(
+        &parse_root("1"),
+        &json!({
+          "$defs": { "a": { "anyOf": [{ "$ref": "#/$defs/a" }] } },
+          "$ref": "#/$defs/a",
+        }),
+        &compile_pattern,
+      )
+      .unwrap_err()
+      .to_string(),
+      "Invalid schema at '/$defs/a/anyOf/0/$ref': references don't reach a value to validate"
+    );
+    assert_eq!(
+      error(json!({ "properties": { "a": { "pattern": "(" } } })),
+      "Invalid schema at '/properties/a/pattern': invalid pattern '(': unterminated group"
+    );
+    assert_eq!(
+      error(json!({ "type": "text" })),
+      "Invalid schema at '/type': unknown type 'text'"
+    );
+  }
+}
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstObject;
+
+use crate::reorder;
+
+/// Compares property names by UTF-16 code units like JavaScript's default
+/// sort order.
+pub fn compare_names(a: &str, b: &str) -> Ordering {
+  a.encode_utf16().cmp(b.encode_utf16())
+}
+
+/// Sorts the properties of the object, and optionally the properties of
+/// all objects within its values.
+///
+/// See `reorder::Plan` for how properties are moved. Properties keep their
+/// comments. When the comparison function fails, the error is returned and
+/// the object is left unchanged.
+pub fn sort_properties<E>(
+  obj: &CstObject,
+  deep: bool,
+  compare: &mut impl FnMut(&str, &str) -> Result<Ordering, E>,
+) -> Result<(), E> {
+  let mut objects = Vec::new();
+  if deep {
+    collect_descendant_objects(&obj.clone().into(), &mut objects);
+  }
+  objects.push(obj.clone());
+
+  // plan every object before changing anything so a failed comparison leaves
+  // the document unchanged
+  let plans = objects
+    .iter()
+    .map(|obj| plan(obj, compare))
+    .collect::<Result<Vec<_>, _>>()?;
+  for (obj, plan) in objects.iter().zip(plans) {
+    reorder::execute_properties(obj, plan);
+  }
+  Ok(())
+}
+
+fn plan<E>(
+  obj: &CstObject,
+  compare: &mut impl FnMut(&str, &str) -> Result<Ordering, E>,
+) -> Result<reorder::Plan, E> {
+  let names = obj
+    .properties()
+    .iter()
+    .map(|prop| {
+      prop
+        .name()
+        .and_then(|name| name.decoded_value().ok())
+        .unwrap_or_default()
+    })
+    .collect::<Vec<_>>();
+
+  // stable binary insertion sort, which is fine with an inconsistent
+  // comparison function unlike the standard library's sorts
+  let mut sorted: Vec<usize> = Vec::with_capacity(names.len());
+  for index in 0..names.len() {
+    let mut low = 0;
+    let mut high = sorted.len();
+    while low < high {
+      let mid = (low + high) / 2;
+      let ordering = compare(&names[sorted[mid]], &names[index])?;
+      if ordering == Ordering::Greater {
+        high = mid;
+      } else {
+        low = mid + 1;
+      }
+    }
+    sorted.insert(low, index);
+  }
+
+  Ok(reorder::Plan::new(sorted))
+}
+
+/// Collects the objects within the node's values, with descendants before
+/// their ancestors.
+fn collect_descendant_objects(node: &CstNode, objects: &mut Vec<CstObject>) {
+  let children = match node {
+    CstNode::Container(CstContainerNode::Object(obj)) => obj
+      .properties()
+      .into_iter()
+      .filter_map(|prop| prop.value())
+      .collect(),
+    CstNode::Container(CstContainerNode::Array(array)) => array.elements(),
+    _ => Vec::new(),
+  };
+  for child in children {
+    collect_descendant_objects(&child, objects);
+    if let Some(obj) = child.as_object() {
+      objects.push(obj);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+  use jsonc_parser::cst::CstRootNode;
+
+  use super::*;
+
+  fn sort(text: &str, deep: bool) -> String {
+    let root = CstRootNode::parse(text, &ParseOptions::default()).unwrap();
+    sort_properties(&root.object_value().unwrap(), deep, &mut |a, b| {
+      Ok::<_, ()>(compare_names(a, b))
+    })
+    .unwrap();
+    root.to_string()
+  }
+
+  #[test]
+  fn moves_comments_with_properties() {
+    assert_eq!(
+      sort(
+        "{\n  // b doc\n  \"b\": 1,\n  // a doc\n  \"a\": 2\n}",
+        false
+      ),
+      "{\n  // a doc\n  \"a\": 2,\n  // b doc\n  \"b\": 1\n}"
+    );
+    // a comment after the comma is a trailing comment of that property
+    assert_eq!(
+      sort("{ // b doc\n \"b\": 1, // b note\n \"a\": 2 }", false),
+      "{\n \"a\": 2,\n // b doc\n \"b\": 1 // b note\n}"
+    );
+    assert_eq!(
+      sort(
+        concat!(
+          "{\n  // about c\n  \"c\": { \"y\": 1, \"x\": 2 }, // c\n",
+          "  /* about b */ \"b\": 2,\n  \"a\": 3\n}",
+        ),
+        true
+      ),
+      concat!(
+        "{\n  \"a\": 3,\n  /* about b */ \"b\": 2,\n  // about c\n",
+        "  \"c\": { \"x\": 2, \"y\": 1 } // c\n}",
+      )
+    );
+  }
+}
@@ -0,0 +1,251 @@
+use jsonc_parser::cst::CstLeafNode;
+use jsonc_parser::cst::CstNewlineKind;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstRootNode;
+
+/// Changes every newline in the document to the kind, including those within
+/// block comments.
+pub fn set_newline_kind(root: &CstRootNode, kind: CstNewlineKind) {
+  set_newline_kind_within(&root.clone().into(), kind);
+}
+
+/// Changes every newline within the node to the kind, including those within
+/// block comments.
+pub(crate) fn set_newline_kind_within(node: &CstNode, kind: CstNewlineKind) {
+  let newline = match kind {
+    CstNewlineKind::LineFeed => "\n",
+    CstNewlineKind::CarriageReturnLineFeed => "\r\n",
+  };
+  for leaf in leaves(node) {
+    match leaf {
+      CstLeafNode::Newline(node) => node.set_kind(kind),
+      CstLeafNode::Comment(comment) if !comment.is_line_comment() => {
+        let raw = comment.raw_value();
+        if raw.contains('\n') {
+          comment.set_raw_value(raw.lines().collect::<Vec<_>>().join(newline));
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Changes a single level of indentation to the text, re-indenting every
+/// line that starts with the document's current indentation. Other
+/// whitespace is left as is.
+///
+/// Returns `false` when the document has no indentation to change.
+pub fn set_indent_text(root: &CstRootNode, indent_text: &str) -> bool {
+  let Some(old_indent) = root.single_indent_text().filter(|t| !t.is_empty())
+  else {
+    return false;
+  };
+  let reindent = |text: &str| {
+    let mut rest = text;
+    let mut indent = String::new();
+    while let Some(remaining) = rest.strip_prefix(old_indent.as_str()) {
+      indent.push_str(indent_text);
+      rest = remaining;
+    }
+    indent.push_str(rest);
+    indent
+  };
+  reindent_lines(&root.clone().into(), true, &reindent);
+  true
+}
+
+/// Re-indents the lines within the node that start with the `old`
+/// indentation to start with `new` instead, such as after moving the node to
+/// a different depth. The line the node starts on is left as is.
+pub(crate) fn reindent(node: &CstNode, old: &str, new: &str) {
+  if old == new {
+    return;
+  }
+  let reindent = |text: &str| match text.strip_prefix(old) {
+    Some(rest) => format!("{}{}", new, rest),
+    None => text.to_string(),
+  };
+  reindent_lines(node, false, &reindent);
+}
+
+/// Gets the indentation of the line the node starts on.
+pub(crate) fn line_indent(node: &CstNode) -> String {
+  // the text before the node on its line, from the last node to the first
+  let mut texts = Vec::new();
+  let mut current = node.clone();
+  loop {
+    let found_line_start = current
+      .previous_siblings()
+      .any(|sibling| push_line_end(&sibling, &mut texts));
+    match current.parent() {
+      Some(parent) if !found_line_start => current = parent.into(),
+      _ => break,
+    }
+  }
+  texts
+    .iter()
+    .rev()
+    .flat_map(|text| text.chars())
+    .take_while(|c| *c == ' ' || *c == '\t')
+    .collect()
+}
+
+/// Pushes the text of the node's leaves from the last to the first until
+/// reaching a newline, which is when `true` is returned.
+fn push_line_end(node: &CstNode, texts: &mut Vec<String>) -> bool {
+  match node {
+    CstNode::Leaf(leaf) => {
+      let text = leaf.to_string();
+      match text.rfind('\n') {
+        Some(index) => {
+          texts.push(text[index + 1..].to_string());
+          true
+        }
+        None => {
+          texts.push(text);
+          false
+        }
+      }
+    }
+    CstNode::Container(container) => container
+      .children()
+      .iter()
+      .rev()
+      .any(|child| push_line_end(child, texts)),
+  }
+}
+
+/// Replaces the indentation of the lines within the node, including those
+/// within block comments. `starts_line` is whether the node's first leaf is
+/// at the start of a line.
+fn reindent_lines(
+  node: &CstNode,
+  starts_line: bool,
+  reindent: &impl Fn(&str) -> String,
+) {
+  // whitespace at the start of a line, which may be split across nodes when
+  // values were inserted
+  let mut line_start: Option<Vec<CstLeafNode>> = starts_line.then(Vec::new);
+  for leaf in leaves(node) {
+    let node: CstNode = leaf.clone().into();
+    if matches!(leaf, CstLeafNode::Newline(_)) {
+      line_start = Some(Vec::new());
+      continue;
+    }
+    if node.is_whitespace() {
+      if let Some(nodes) = &mut line_start {
+        nodes.push(leaf);
+      }
+      continue;
+    }
+    if let Some(nodes) = line_start.take() {
+      set_whitespace(&nodes, reindent);
+    }
+    if let CstLeafNode::Comment(comment) = &leaf {
+      let raw = comment.raw_value();
+      if raw.contains('\n') {
+        let newline = if raw.contains("\r\n") { "\r\n" } else { "\n" };
+        let mut lines = raw.lines();
+        let mut text = lines.next().unwrap_or_default().to_string();
+        for line in lines {
+          text.push_str(newline);
+          text.push_str(&reindent(line));
+        }
+        comment.set_raw_value(text);
+      }
+    }
+  }
+}
+
+/// Replaces the combined text of the whitespace nodes.
+fn set_whitespace(nodes: &[CstLeafNode], replace: &impl Fn(&str) -> String) {
+  let Some((first, rest)) = nodes.split_first() else {
+    return;
+  };
+  let text = nodes.iter().map(|n| n.to_string()).collect::<String>();
+  set_leaf_text(first, replace(&text));
+  for node in rest {
+    set_leaf_text(node, String::new());
+  }
+}
+
+fn set_leaf_text(node: &CstLeafNode, text: String) {
+  if let CstLeafNode::Whitespace(whitespace) = node {
+    whitespace.set_value(text);
+  }
+}
+
+/// Gets the leaf nodes within the node in document order.
+pub(crate) fn leaves(node: &CstNode) -> Vec<CstLeafNode> {
+  match node {
+    CstNode::Leaf(leaf) => vec![leaf.clone()],
+    CstNode::Container(container) => {
+      container.children().iter().flat_map(leaves).collect()
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use jsonc_parser::ParseOptions;
+
+  use super::*;
+
+  fn parse_root(text: &str) -> CstRootNode {
+    CstRootNode::parse(text, &ParseOptions::default()).unwrap()
+  }
+
+  #[test]
+  fn changes_newline_kinds() {
+    let root = parse_root("{\n  \"a\": 1\n}\n");
+    set_newline_kind(&root, CstNewlineKind::CarriageReturnLineFeed);
+    assert_eq!(root.to_string(), "{\r\n  \"a\": 1\r\n}\r\n");
+    set_newline_kind(&root, CstNewlineKind::LineFeed);
+    assert_eq!(root.to_string(), "{\n  \"a\": 1\n}\n");
+  }
+
+  #[test]
+  fn changes_newline_kinds_around_and_within_comments() {
+    let text = "// header\n{\n  /* a\n   * doc\n   */\n  \"a\": 1, // one\n  /* b */ \"b\": [\n    2 // two\n  ]\n}";
+    let root = parse_root(text);
+    set_newline_kind(&root, CstNewlineKind::CarriageReturnLineFeed);
+    assert_eq!(root.to_string(), text.replace('\n', "\r\n"));
+    set_newline_kind(&root, CstNewlineKind::LineFeed);
+    assert_eq!(root.to_string(), text);
+  }
+
+  #[test]
+  fn reindents_multiline_block_comments() {
+    let root = parse_root(
+      "{\n  /**\n   * doc\n   *\n   */\n  \"a\": {\n    /* x\n       y */\n    \"b\": 1\n  }\n}",
+    );
+    assert!(set_indent_text(&root, "    "));
+    assert_eq!(
+      root.to_string(),
+      "{\n    /**\n     * doc\n     *\n     */\n    \"a\": {\n        /* x\n             y */\n        \"b\": 1\n    }\n}"
+    );
+  }
+
+  #[test]
+  fn reindents_nested_containers_with_tabs() {
+    let text = "{\n  \"a\": [\n    {\n      \"b\": [1, 2],\n      \"c\": [\n        3\n      ]\n    }\n  ],\n  \"d\": \"  x\"\n}";
+    let root = parse_root(text);
+    assert!(set_indent_text(&root, "\t"));
+    assert_eq!(
+      root.to_string(),
+      "{\n\t\"a\": [\n\t\t{\n\t\t\t\"b\": [1, 2],\n\t\t\t\"c\": [\n\t\t\t\t3\n\t\t\t]\n\t\t}\n\t],\n\t\"d\": \"  x\"\n}"
+    );
+    assert!(set_indent_text(&root, "  "));
+    assert_eq!(root.to_string(), text);
+  }
+
+  #[test]
+  fn changes_indent_text() {
+    let root = parse_root("{\n  \"a\": [\n    1\n  ]\n}");
+    assert!(set_indent_text(&root, "    "));
+    assert_eq!(root.to_string(), "{\n    \"a\": [\n        1\n    ]\n}");
+    let root = parse_root("{ \"a\": 1 }");
+    assert!(!set_indent_text(&root, "    "));
+    assert_eq!(root.to_string(), "{ \"a\": 1 }");
+  }
+}
@@ -0,0 +1,634 @@
+use std::fmt;
+
+use jsonc_parser::ParseOptions;
+use jsonc_parser::cst::CstArray;
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstInputValue;
+use jsonc_parser::cst::CstLeafNode;
+use jsonc_parser::cst::CstNewlineKind;
+use jsonc_parser::cst::CstNode;
+use jsonc_parser::cst::CstObject;
+use jsonc_parser::cst::CstObjectProp;
+use jsonc_parser::cst::CstRootNode;
+
+use crate::comments;
+use crate::layout;
+use crate::trivia;
+
+/// Inserts a value at the index of the array's elements. The index may be
+/// the number of elements to append.
+pub(crate) fn insert_element(
+  array: &CstArray,
+  index: usize,
+  value: CstInputValue,
+) -> CstNode {
+  collapse_comma_space(array.insert(index, value))
+}
+
+/// Removes the extra space jsonc-parser adds after the comma it inserts
+/// when one already follows it (ex. `[1, 9,  2]`).
+fn collapse_comma_space(element: CstNode) -> CstNode {
+  let siblings = element.next_siblings().collect::<Vec<_>>();
+  if siblings.first().is_some_and(|n| n.is_comma()) {
+    for extra in siblings[1..]
+      .iter()
+      .take_while(|n| n.is_whitespace())
+      .skip(1)
+    {
+      extra.clone().remove();
+    }
+  }
+  element
+}
+
+/// Removes the object property or array element.
+///
+/// jsonc-parser keeps the space after the comma when removing a value
+/// directly after the opening brace or bracket (ex. `[1, 2]` becomes
+/// `[ 2]`), which is removed.
+pub fn remove(node: CstNode) {
+  let previous = node.previous_sibling();
+  let follows_open_token = previous
+    .and_then(|p| p.as_token())
+    .is_some_and(|token| matches!(token.value(), '{' | '['));
+  let parent = node.parent();
+  node.remove();
+  if follows_open_token
+    && let Some(whitespace) = parent
+      .and_then(|p| p.children().into_iter().nth(1))
+      .filter(|n| n.is_whitespace())
+  {
+    whitespace.remove();
+  }
+}
+
+/// A property or array element that was removed from its document along
+/// with its comments, so it can be inserted elsewhere as is.
+pub(crate) struct DetachedNode {
+  node: CstNode,
+  comments: comments::DetachedComments,
+  /// The indentation of the line the node started on.
+  indent: String,
+  newline_kind: CstNewlineKind,
+}
+
+impl DetachedNode {
+  /// Copies the node, or a property's value, from this or another document.
+  /// The comments around a property or array element are copied too.
+  pub(crate) fn copy(source: &CstNode) -> Option<Self> {
+    let value = match source.as_object_prop() {
+      Some(prop) => prop.value()?,
+      None => source.clone(),
+    };
+    let has_comments = source.as_object_prop().is_some()
+      || matches!(source.parent(), Some(CstContainerNode::Array(_)));
+    let comments = if has_comments {
+      comments::DetachedComments::copy(source)
+    } else {
+      Default::default()
+    };
+    let indent = if source.parent().is_some() {
+      trivia::line_indent(source)
+    } else {
+      // a node without a parent was indented like its last line, such as
+      // the closing brace of an object
+      let text = source.to_string();
+      let last_line = text.rsplit('\n').next().unwrap_or_default();
+      last_line
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+    };
+    Some(Self {
+      node: detached_copy(&value)?,
+      comments,
+      indent,
+      newline_kind: newline_kind(source),
+    })
+  }
+
+  /// Takes the value out of a document parsed from text to insert, along with
+  /// the comments around it.
+  pub(crate) fn take_root_value(value: &CstNode) -> Self {
+    let comments = comments::DetachedComments::copy_around_root_value(value);
+    let indent = trivia::line_indent(value);
+    let newline_kind = newline_kind(value);
+    take_from_temporary(value);
+    Self {
+      node: value.clone(),
+      comments,
+      indent,
+      newline_kind,
+    }
+  }
+
+  /// Removes the property or array element from its document.
+  pub(crate) fn remove(node: &CstNode) -> Self {
+    let indent = trivia::line_indent(node);
+    let newline_kind = newline_kind(node);
+    let comments = comments::DetachedComments::remove(node);
+    remove(node.clone());
+    Self {
+      node: node.clone(),
+      comments,
+      indent,
+      newline_kind,
+    }
+  }
+
+  /// Removes the value from its document, along with its property when the
+  /// value is in an object. The comments around the property go with the
+  /// value.
+  pub(crate) fn remove_value(value: &CstNode) -> Self {
+    match value.parent() {
+      Some(CstContainerNode::ObjectProp(prop)) => {
+        let removed = Self::remove(&prop.clone().into());
+        // the property is kept alive while the value is taken out of it
+        take_from_temporary(value);
+        Self {
+          node: value.clone(),
+          ..removed
+        }
+      }
+      _ => Self::remove(value),
+    }
+  }
+
+  /// Inserts the property at the index of the object's properties.
+  ///
+  /// Panics if the node isn't a property.
+  pub(crate) fn insert_property(
+    self,
+    obj: &CstObject,
+    index: usize,
+  ) -> CstObjectProp {
+    // a line comment would comment out the rest of a single-line object
+    if self.comments.has_line_comment()
+      && !layout::is_multiline(&obj.clone().into())
+    {
+      obj.ensure_multiline();
+    }
+    let prop = obj.insert_node(index, self.node.as_object_prop().unwrap());
+    self.attach(&prop.clone().into());
+    prop
+  }
+
+  /// Inserts the value as a new property with the name at the index of the
+  /// object's properties.
+  pub(crate) fn insert_value_property(
+    self,
+    obj: &CstObject,
+    index: usize,
+    name: &str,
+  ) -> CstObjectProp {
+    let prop = obj.insert_with_node(index, name, self.node.clone());
+    self.attach(&prop.clone().into());
+    prop
+  }
+
+  /// Inserts the value at the index of the array's elements.
+  pub(crate) fn insert_element(
+    self,
+    array: &CstArray,
+    index: usize,
+  ) -> CstNode {
+    // a line comment would comment out the rest of a single-line array
+    if self.comments.has_line_comment()
+      && !layout::is_multiline(&array.clone().into())
+    {
+      array.ensure_multiline();
+    }
+    let element =
+      collapse_comma_space(array.insert_node(index, self.node.clone()));
+    self.attach(&element);
+    element
+  }
+
+  /// Sets the value of the root node.
+  pub(crate) fn set_root_value(self, root: &CstRootNode) -> CstNode {
+    let node = self.node.clone();
+    root.set_value_node(node.clone());
+    self.attach(&node);
+    node
+  }
+
+  /// Sets the value of the property. The comments go around the property.
+  pub(crate) fn set_property_value(self, prop: &CstObjectProp) -> CstNode {
+    let node = self.node.clone();
+    prop.set_value_node(node.clone());
+    self.attach(&node);
+    node
+  }
+
+  /// Replaces the value node. The comments go around its property when the
+  /// value is in an object.
+  pub(crate) fn replace(self, node: CstNode) -> Option<CstNode> {
+    let inserted = node.replace_with_node(self.node.clone())?;
+    self.attach(&inserted);
+    Some(inserted)
+  }
+
+  /// Adapts the inserted node and its comments to their new place, then
+  /// inserts the comments around it, or around its property.
+  fn attach(self, inserted: &CstNode) {
+    let comments = self.comments.nodes().into_iter().map(CstNode::from);
+    let nodes = std::iter::once(inserted.clone())
+      .chain(comments)
+      .collect::<Vec<_>>();
+    let newline_kind = newline_kind(inserted);
+    let indent = trivia::line_indent(inserted);
+    for node in &nodes {
+      if newline_kind != self.newline_kind {
+        trivia::set_newline_kind_within(node, newline_kind);
+      }
+      trivia::reindent(node, &self.indent, &indent);
+    }
+    let element = match inserted.parent() {
+      Some(CstContainerNode::ObjectProp(prop)) => prop.into(),
+      _ => inserted.clone(),
+    };
+    self.comments.attach(&element);
+  }
+}
+
+/// Gets the newline kind of the node's document.
+fn newline_kind(node: &CstNode) -> CstNewlineKind {
+  node
+    .root_node()
+    .map(|root| root.newline_kind())
+    .unwrap_or_default()
+}
+
+/// Creates a copy of the node that has no parent by parsing its text.
+pub fn detached_copy(node: &CstNode) -> Option<CstNode> {
+  let options = ParseOptions::default();
+  let is_prop = node.as_object_prop().is_some();
+  let text = if is_prop {
+    format!("{{{}}}", node)
+  } else {
+    node.to_string()
+  };
+  let root = CstRootNode::parse(&text, &options).ok()?;
+  let copy: CstNode = if is_prop {
+    root.object_value()?.properties().into_iter().next()?.into()
+  } else {
+    root
+      .value()
+      .or_else(|| root.children().into_iter().next())?
+  };
+  take_from_temporary(&copy);
+  Some(copy)
+}
+
+/// Takes the node out of a document that's about to be dropped, such as one
+/// parsed only to get the node.
+///
+/// Nodes only hold weak references to their parents, and jsonc-parser panics
+/// when walking up to a parent that was dropped. So a node is taken out of
+/// its document before the document is dropped, and a node whose ancestor
+/// was removed is only used while that ancestor is kept alive.
+pub(crate) fn take_from_temporary(node: &CstNode) {
+  node.clone().remove();
+}
+
+/// Gets if the node is part of a document, which is false once it or one of
+/// its ancestors was removed or replaced, and for copies made by
+/// `detached_copy`.
+///
+/// A removed ancestor must be kept alive, as described in
+/// `take_from_temporary`.
+pub fn is_attached(node: &CstNode) -> bool {
+  node.as_root_node().is_some() || node.root_node().is_some()
+}
+
+/// Gets the index of the node in its parent array's elements.
+pub(crate) fn element_index(node: &CstNode) -> Option<usize> {
+  let Some(CstContainerNode::Array(array)) = node.parent() else {
+    return None;
+  };
+  let child_index = node.child_index();
+  array
+    .elements()
+    .iter()
+    .position(|element| element.child_index() == child_index)
+}
+
+/// Converts the node to a serde value.
+pub fn to_serde_value(node: &CstNode) -> Option<serde_json::Value> {
+  if let Some(prop) = node.as_object_prop() {
+    return to_serde_value(&prop.value()?);
+  }
+  node.to_serde_value()
+}
+
+/// Converts a JSON value to an input value for inserting into a document.
+///
+/// Integers that fit in an `i64` or `u64` keep all their digits, even those
+/// an `f64` can't represent. `serde_json` stores larger integers as an `f64`
+/// (without its `arbitrary_precision` feature), so they lose precision.
+pub fn from_serde_value(value: serde_json::Value) -> CstInputValue {
+  match value {
+    serde_json::Value::Null => CstInputValue::Null,
+    serde_json::Value::Bool(b) => CstInputValue::from(b),
+    serde_json::Value::Number(n) => match n.as_f64() {
+      Some(f) if n.is_f64() => CstInputValue::from(f),
+      _ => CstInputValue::Number(n.to_string()),
+    },
+    serde_json::Value::String(s) => CstInputValue::from(s),
+    serde_json::Value::Array(arr) => {
+      let converted: Vec<CstInputValue> =
+        arr.into_iter().map(from_serde_value).collect();
+      CstInputValue::from(converted)
+    }
+    serde_json::Value::Object(obj) => {
+      let converted: Vec<(String, CstInputValue)> = obj
+        .into_iter()
+        .map(|(k, v)| (k, from_serde_value(v)))
+        .collect();
+      CstInputValue::from(converted)
+    }
+  }
+}
+
+/// Converts a value node to an input value, keeping the original
+/// spelling of numbers (ex. `0xFF` or `1.50`).
+pub fn to_cst_input(node: &CstNode) -> Option<CstInputValue> {
+  match node {
+    CstNode::Container(CstContainerNode::Object(obj)) => {
+      let props = obj
+        .properties()
+        .into_iter()
+        .filter_map(|prop| {
+          let name = prop.name()?.decoded_value().ok()?;
+          let value = to_cst_input(&prop.value()?)?;
+          Some((name, value))
+        })
+        .collect::<Vec<_>>();
+      Some(CstInputValue::Object(props))
+    }
+    CstNode::Container(CstContainerNode::Array(arr)) => {
+      let elements = arr
+        .elements()
+        .iter()
+        .filter_map(to_cst_input)
+        .collect::<Vec<_>>();
+      Some(CstInputValue::Array(elements))
+    }
+    CstNode::Leaf(CstLeafNode::BooleanLit(b)) => {
+      Some(CstInputValue::Bool(b.value()))
+    }
+    CstNode::Leaf(CstLeafNode::NullKeyword(_)) => Some(CstInputValue::Null),
+    CstNode::Leaf(CstLeafNode::NumberLit(n)) => {
+      Some(CstInputValue::Number(n.to_string()))
+    }
+    CstNode::Leaf(CstLeafNode::StringLit(s)) => {
+      s.decoded_value().ok().map(CstInputValue::String)
+    }
+    CstNode::Leaf(CstLeafNode::WordLit(w)) => {
+      Some(CstInputValue::String(w.to_string()))
+    }
+    _ => None,
+  }
+}
+
+/// A value to insert into a document, which is either a new value or a copy
+/// of a node from this or another document.
+///
+/// Copies and parsed values are inserted as is, keeping the formatting and
+/// comments within them. The comments around a copied property or array
+/// element, or around a parsed value, go around the property or element
+/// it's inserted as.
+pub struct InsertValue(InsertKind);
+
+enum InsertKind {
+  New(CstInputValue),
+  Copy(DetachedNode),
+}
+
+/// Error that occurs when a value to insert can't be created.
+#[derive(Debug)]
+pub enum InsertValueError {
+  /// The text can't be parsed.
+  Parse(jsonc_parser::errors::ParseError),
+  /// The node or text has no value to insert.
+  NoValue,
+}
+
+impl fmt::Display for InsertValueError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      InsertValueError::Parse(err) => write!(f, "{}", err),
+      InsertValueError::NoValue => write!(f, "Expected a value to insert"),
+    }
+  }
+}
+
+impl InsertValue {
+  /// Copies a node, or a property's value, along with its comments.
+  pub fn copy_of(node: &CstNode) -> Result<Self, InsertValueError> {
+    DetachedNode::copy(node)
+      .map(|copy| InsertValue(InsertKind::Copy(copy)))
+      .ok_or(InsertValueError::NoValue)
+  }
+
+  /// Parses the JSONC text of a value, which is inserted as is along with
+  /// its comments.
+  pub fn parse(
+    text: &str,
+    options: &ParseOptions,
+  ) -> Result<Self, InsertValueError> {
+    let root =
+      CstRootNode::parse(text, options).map_err(InsertValueError::Parse)?;
+    let value = root.value().ok_or(InsertValueError::NoValue)?;
+    let value = DetachedNode::take_root_value(&value);
+    Ok(InsertValue(InsertKind::Copy(value)))
+  }
+
+  /// Sets the value of the root node and gets the inserted value node.
+  pub fn set_root_value(self, root: &CstRootNode) -> CstNode {
+    match self.0 {
+      InsertKind::New(input) => {
+        root.set_value(input);
+        root.value().unwrap()
+      }
+      InsertKind::Copy(copy) => copy.set_root_value(root),
+    }
+  }
+
+  /// Sets the value of the property and gets the inserted value node.
+  pub fn set_property_value(self, prop: &CstObjectProp) -> CstNode {
+    match self.0 {
+      InsertKind::New(input) => {
+        prop.set_value(input);
+        prop.value().unwrap()
+      }
+      InsertKind::Copy(copy) => copy.set_property_value(prop),
+    }
+  }
+
+  /// Inserts the value as a new property with the name at the index of the
+  /// object's properties.
+  pub fn insert_property(
+    self,
+    obj: &CstObject,
+    index: usize,
+    name: &str,
+  ) -> CstObjectProp {
+    match self.0 {
+      InsertKind::New(input) => obj.insert(index, name, input),
+      InsertKind::Copy(copy) => copy.insert_value_property(obj, index, name),
+    }
+  }
+
+  /// Inserts the value at the index of the array's elements.
+  pub fn insert_element(self, array: &CstArray, index: usize) -> CstNode {
+    match self.0 {
+      InsertKind::New(input) => insert_element(array, index, input),
+      InsertKind::Copy(copy) => copy.insert_element(array, index),
+    }
+  }
+
+  /// Replaces the value node and gets the inserted value node.
+  pub fn replace(self, node: CstNode) -> Option<CstNode> {
+    match self.0 {
+      InsertKind::New(input) => crate::pointer::replace_value(node, input),
+      InsertKind::Copy(copy) => copy.replace(node),
+    }
+  }
+}
+
+impl From<DetachedNode> for InsertValue {
+  fn from(value: DetachedNode) -> Self {
+    InsertValue(InsertKind::Copy(value))
+  }
+}
+
+impl From<serde_json::Value> for InsertValue {
+  fn from(value: serde_json::Value) -> Self {
+    InsertValue(InsertKind::New(from_serde_value(value)))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use serde_json::json;
+
+  use super::*;
+
+  fn parse_root(text: &str) -> CstRootNode {
+    CstRootNode::parse(text, &ParseOptions::default()).unwrap()
+  }
+
+  #[test]
+  fn converts_serde_values() {
+    let root = parse_root("[]");
+    root.set_value(from_serde_value(json!({
+      "a": [null, true, 1.5, "s"],
+      "b": 9007199254740993u64,
+    })));
+    assert_eq!(
+      root.to_string(),
+      "{\n  \"a\": [null, true, 1.5, \"s\"],\n  \"b\": 9007199254740993\n}"
+    );
+  }
+
+  #[test]
+  fn converts_integers_beyond_u64_to_floats() {
+    // u64::MAX, u64::MAX + 1 and i64::MIN
+    let value = serde_json::from_str(
+      "[18446744073709551615, 18446744073709551616, -9223372036854775808]",
+    )
+    .unwrap();
+    let root = parse_root("null");
+    root.set_value(from_serde_value(value));
+    assert_eq!(
+      root.to_string(),
+      "[18446744073709551615, 18446744073709552000, -9223372036854775808]"
+    );
+  }
+
+  #[test]
+  fn converts_nodes_keeping_number_text() {
+    let source = parse_root("{ a: [0xFF, 1.50, 'b'] }");
+    let value = source.value().unwrap();
+    let root = parse_root("null");
+    root.set_value(to_cst_input(&value).unwrap());
+    assert_eq!(root.to_string(), "{\n  \"a\": [0xFF, 1.50, \"b\"]\n}");
+    assert_eq!(
+      to_serde_value(&value).unwrap(),
+      json!({ "a": [255, 1.5, "b"] })
+    );
+  }
+
+  #[test]
+  fn appends_to_multiline_arrays() {
+    let root = parse_root("[\n  1\n]");
+    let array = root.array_value().unwrap();
+    insert_element(&array, 1, CstInputValue::from(2.0));
+    assert_eq!(array.elements().len(), 2);
+    assert_eq!(root.to_string(), "[\n  1,\n  2\n]");
+    assert_eq!(to_serde_value(&array.clone().into()), Some(json!([1, 2])));
+    remove(array.elements()[1].clone());
+    assert_eq!(root.to_string(), "[\n  1\n]");
+  }
+
+  #[test]
+  fn inserts_copies_with_their_comments() {
+    let source = parse_root(
+      "{\n  // a doc\n  \"a\": { 'b': 0xFF /* x */ }, // a note\n  \"c\": [\n    1, // one\n    2\n  ]\n}",
+    );
+    let source_obj = source.object_value().unwrap();
+    let root = parse_root("{\n  \"x\": [\n    true\n  ]\n}");
+    let obj = root.object_value().unwrap();
+    let a = InsertValue::copy_of(&source_obj.get("a").unwrap().into());
+    a.unwrap().insert_property(&obj, 0, "a");
+    let array = obj.get("x").unwrap().value().unwrap().as_array().unwrap();
+    let c = source_obj.get("c").unwrap().value().unwrap();
+    let c = InsertValue::copy_of(&c).unwrap();
+    c.insert_element(&array, 1);
+    let one = source_obj.array_value("c").unwrap().elements()[0].clone();
+    let one = InsertValue::copy_of(&one).unwrap();
+    one.insert_element(&array, 0);
+    assert_eq!(
+      root.to_string(),
+      "{\n  // a doc\n  \"a\": { 'b': 0xFF /* x */ }, // a note\n  \"x\": [\n    1, // one\n    true,\n    [\n      1, // one\n      2\n    ]\n  ]\n}"
+    );
+  }
+
+  #[test]
+  fn inserts_parsed_values_with_their_comments() {
+    let options = ParseOptions {
+      allow_single_quoted_strings: false,
+      ..Default::default()
+    };
+    let root = CstRootNode::parse("{ \"a\": 1 }", &options).unwrap();
+    let options = root.parse_options();
+    assert!(!options.allow_single_quoted_strings);
+    assert!(InsertValue::parse("'b'", options).is_err());
+    let prop = root.object_value().unwrap().get("a").unwrap();
+    let value = "/* list */ [\n  1, // one\n  2\n] // two";
+    InsertValue::parse(value, options)
+      .unwrap()
+      .set_property_value(&prop);
+    assert_eq!(
+      root.to_string(),
+      "{ /* list */ \"a\": [\n  1, // one\n  2\n] // two\n}"
+    );
+  }
+
+  #[test]
+  fn detects_detached_nodes() {
+    let root = parse_root("{ \"a\": { \"b\": [1] }, \"c\": 2 }");
+    let obj = root.object_value().unwrap();
+    let a = obj.get("a").unwrap();
+    let element = a.object_value().unwrap().get("b").unwrap().value().unwrap();
+    assert!(is_attached(&root.clone().into()));
+    assert!(is_attached(&element));
+    let copy = detached_copy(&element).unwrap();
+    assert!(!is_attached(&copy));
+    a.clone().remove();
+    assert!(!is_attached(&a.clone().into()));
+    assert!(!is_attached(&element));
+    assert!(is_attached(&obj.get("c").unwrap().into()));
+  }
+}
@@ -0,0 +1,4209 @@
+use std::cell::RefCell;
+
+use js_sys::JsString;
+use jsonc_parser::ParseOptions;
+use jsonc_parser::cst;
+use jsonc_parser::cst::CstContainerNode;
+use jsonc_parser::cst::CstInputValue;
+use jsonc_parser::cst::CstLeafNode;
+use jsonc_parser::cst::CstNode as JsoncCstNode;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::comments;
+use crate::diff;
+use crate::errors::ErrorCode;
+use crate::errors::JsoncMorphError;
+use crate::errors::ParseError;
+use crate::format;
+use crate::layout;
+use crate::merge;
+use crate::numbers;
+use crate::patch;
+use crate::pointer;
+use crate::position;
+use crate::prop_name;
+use crate::reorder;
+use crate::schema;
+use crate::sort;
+use crate::trivia;
+use crate::values;
+
+fn throw_error(msg: &str) -> JsValue {
+  js_sys::Error::new(msg).into()
+}
+
+/// Gets the ancestors of the node for its wrapper to hold, which keeps them
+/// alive once they're removed, as described in `values::take_from_temporary`.
+fn ancestors(node: &JsoncCstNode) -> Vec<CstContainerNode> {
+  node.ancestors().collect()
+}
+
+/// Throws when the node isn't part of a document, since changes to it would
+/// never reach the document's text.
+fn ensure_attached(node: &JsoncCstNode) -> Result<(), JsValue> {
+  if values::is_attached(node) {
+    Ok(())
+  } else {
+    Err(
+      JsoncMorphError::new(
+        ErrorCode::DetachedNode,
+        "Cannot change a node that was removed from its document",
+      )
+      .into(),
+    )
+  }
+}
+
+pub(crate) fn to_js_object<T: Serialize, R: JsCast>(value: &T) -> R {
+  serde_wasm_bindgen::to_value(value)
+    .unwrap_or(JsValue::UNDEFINED)
+    .unchecked_into()
+}
+
+fn comment_infos(comments: Vec<cst::CstComment>) -> CommentInfos {
+  to_js_object(
+    &comments
+      .iter()
+      .map(comments::CommentInfo::new)
+      .collect::<Vec<_>>(),
+  )
+}
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "ParseOptions")]
+  pub type JsoncParseOptionsObject;
+
+  #[wasm_bindgen(typescript_type = "ParseOptions & ToValueOptions")]
+  pub type ParseToValueOptionsObject;
+
+  #[wasm_bindgen(typescript_type = "ToValueOptions")]
+  pub type ToValueOptionsObject;
+
+  #[wasm_bindgen(typescript_type = "SetNumberOptions")]
+  pub type SetNumberOptionsObject;
+
+  #[wasm_bindgen(typescript_type = "JsonValue")]
+  pub type JsonValue;
+
+  #[wasm_bindgen(typescript_type = "TextRange")]
+  pub type TextRangeObject;
+
+  #[wasm_bindgen(typescript_type = "LineColumn")]
+  pub type LineColumnObject;
+
+  #[wasm_bindgen(typescript_type = "JsonPatchOperation[]")]
+  pub type JsonPatchOperations;
+
+  #[wasm_bindgen(typescript_type = "CommentInfo[]")]
+  pub type CommentInfos;
+
+  #[wasm_bindgen(typescript_type = "ValidationError[]")]
+  pub type ValidationErrors;
+
+  #[wasm_bindgen(typescript_type = "CommentOptions")]
+  pub type CommentOptionsObject;
+
+  #[wasm_bindgen(typescript_type = "(a: string, b: string) => number")]
+  pub type PropertyCompareFn;
+
+  #[wasm_bindgen(typescript_type = "SortOptions")]
+  pub type SortOptionsObject;
+
+  #[wasm_bindgen(typescript_type = "DiffOptions")]
+  pub type DiffOptionsObject;
+
+  #[wasm_bindgen(typescript_type = "FormatOptions")]
+  pub type FormatOptionsObject;
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_APPEND_CONTENT: &'static str = r#"
+/**
+ * Options for parsing JSONC.
+ *
+ * When used with `parse`/`parseToValue`, all options default to `true` (permissive).
+ * When used with `parseStrict`/`parseToValueStrict`, all options default to `false` (strict).
+ */
+export interface ParseOptions {
+  /** Allow comments. */
+  allowComments?: boolean;
+  /** Allow trailing commas. */
+  allowTrailingCommas?: boolean;
+  /** Allow loose object property names (unquoted keys). */
+  allowLooseObjectPropertyNames?: boolean;
+  /** Allow missing commas between elements. */
+  allowMissingCommas?: boolean;
+  /** Allow single-quoted strings. */
+  allowSingleQuotedStrings?: boolean;
+  /** Allow hexadecimal numbers (e.g., 0xFF). */
+  allowHexadecimalNumbers?: boolean;
+  /** Allow unary plus on numbers (e.g., +42). */
+  allowUnaryPlusNumbers?: boolean;
+}
+
+export type JsonValue = string | number | bigint | boolean | null | JsonValue[] | { [key: string]: JsonValue };
+
+/** Options for setting the value of a number literal. */
+export interface SetNumberOptions {
+  /**
+   * Keep the current style of the number where possible, such as
+   * hexadecimal, an exponent, or the number of fraction digits.
+   * Defaults to `false`.
+   */
+  preserveStyle?: boolean;
+}
+
+/** Options for converting to a plain JavaScript value. */
+export interface ToValueOptions {
+  /**
+   * How to convert integers outside the safe integer range, which a number
+   * can't represent exactly (ex. 64-bit IDs). Either a `number`, which may
+   * lose precision like `JSON.parse`, a `BigInt`, or a string of the digits.
+   * Defaults to `"number"`.
+   */
+  largeIntegers?: "number" | "bigint" | "string";
+}
+
+/**
+ * Start and end offsets of a span of text.
+ *
+ * Ranges of nodes are computed when requested, so they're only correct until
+ * the document is next changed.
+ */
+export interface TextRange {
+  /** Start offset (inclusive). */
+  start: number;
+  /** End offset (exclusive). */
+  end: number;
+}
+
+/** A 1-based line and column. Columns are measured in UTF-16 code units. */
+export interface LineColumn {
+  /** 1-based line number. */
+  line: number;
+  /** 1-based column number. */
+  column: number;
+}
+
+/** A JSON Patch (RFC 6902) operation. */
+export type JsonPatchOperation =
+  | { op: "add"; path: string; value: JsonValue }
+  | { op: "remove"; path: string }
+  | { op: "replace"; path: string; value: JsonValue }
+  | { op: "move"; from: string; path: string }
+  | { op: "copy"; from: string; path: string }
+  | { op: "test"; path: string; value: JsonValue };
+
+/** A comment in the document. */
+export interface CommentInfo {
+  /** Whether this is a line (`//`) or block comment. */
+  kind: "line" | "block";
+  /** Text of the comment without the delimiters or surrounding whitespace. */
+  text: string;
+  /** Text of the comment including the delimiters. */
+  raw: string;
+  /** Byte range of the comment in the document's text. */
+  range: TextRange;
+}
+
+/** Options for writing a comment. */
+export interface CommentOptions {
+  /** Whether to write a line (`//`) or block comment. Defaults to `"line"`. */
+  kind?: "line" | "block";
+}
+
+/** Options for sorting properties. */
+export interface SortOptions {
+  /**
+   * Also sort the properties of objects within the values.
+   * Defaults to `false`.
+   */
+  sortKeysDeep?: boolean;
+}
+
+/** Options for computing a diff. */
+export interface DiffOptions {
+  /**
+   * Emit `move` operations for array elements that changed position
+   * instead of removing and re-adding them. Defaults to `false`.
+   */
+  detectMoves?: boolean;
+}
+
+/** A value that doesn't match the schema passed to `validate`. */
+export interface ValidationError {
+  /** Schema keyword that failed (ex. `"type"` or `"required"`). */
+  keyword: string;
+  message: string;
+  /** JSON Pointer of the value that failed. */
+  pointer: string;
+  /**
+   * JSON Pointer of the keyword in the schema, which is within the
+   * definition for keywords reached through a `$ref`.
+   */
+  schemaPointer: string;
+  /** Name of the property that's missing or not allowed. */
+  key?: string;
+  /**
+   * Byte range of the value, or of the whole property when the property
+   * isn't allowed.
+   */
+  range: TextRange;
+  /** Range in UTF-16 code units, for indexing JavaScript strings. */
+  utf16Range: TextRange;
+  /** Line and column where the value or property starts. */
+  start: LineColumn;
+  /** Line and column where the value or property ends. */
+  end: LineColumn;
+}
+
+/** Options for formatting a document. Comments are always kept. */
+export interface FormatOptions {
+  /**
+   * Number of spaces in a level of indentation, from 0 to 16.
+   * Defaults to the document's current indentation.
+   *
+   * With `useTabs`, this is the number of columns a tab counts as when
+   * measuring the `lineWidth`, which defaults to `2`.
+   */
+  indentWidth?: number;
+  /** Indent with tabs instead of spaces. Defaults to `false`. */
+  useTabs?: boolean;
+  /** Newline to use. Defaults to the document's current newline kind. */
+  newlineKind?: "\n" | "\r\n";
+  /**
+   * Maximum line width, up to 100000. Single-line objects and arrays that
+   * don't fit are put on multiple lines. By default, there is no maximum.
+   */
+  lineWidth?: number;
+  /**
+   * Put multi-line objects and arrays on a single line when they fit and
+   * have no line comments. Otherwise, an object or array is multi-line when
+   * its first element is on a different line than its open brace or bracket.
+   * Defaults to `false`.
+   */
+  preferSingleLine?: boolean;
+  /**
+   * Maximum number of consecutive blank lines to keep, up to 1000.
+   * Defaults to `1` when formatting and to keeping every blank line in
+   * `toString`.
+   */
+  maxBlankLines?: number;
+  /** Quotes of strings and quoted property names. Defaults to `"preserve"`. */
+  quoteStyle?: "double" | "single" | "preserve";
+  /**
+   * Whether multi-line objects and arrays have a trailing comma.
+   * By default, each keeps whether it has one.
+   */
+  trailingCommas?: boolean;
+}
+"#;
+
+/// Parses a JSONC (JSON with Comments) string into a concrete syntax tree.
+/// @param text - The JSONC text to parse
+/// @param options - Optional parsing options
+/// @returns The root node of the parsed CST
+/// @throws {ParseError} If the text cannot be parsed
+#[wasm_bindgen]
+pub fn parse(
+  text: &str,
+  options: Option<JsoncParseOptionsObject>,
+) -> Result<RootNode, JsValue> {
+  let parse_options = match options {
+    Some(opts) => parse_options_from_js(&opts.into()),
+    None => ParseOptions::default(),
+  };
+
+  let root = cst::CstRootNode::parse(text, &parse_options)
+    .map_err(|e| ParseError::new(&e, text))?;
+  Ok(RootNode { inner: root })
+}
+
+/// Parses a JSONC (JSON with Comments) string directly to a JavaScript object.
+/// @param text - The JSONC text to parse
+/// @param options - Optional parsing and conversion options
+/// @returns The plain JavaScript value (object, array, string, number, boolean, or null)
+/// @throws {ParseError} If the text cannot be parsed
+/// @throws If the value cannot be converted
+#[wasm_bindgen(js_name = parseToValue)]
+pub fn parse_to_value(
+  text: &str,
+  options: Option<ParseToValueOptionsObject>,
+) -> Result<JsValue, JsValue> {
+  let (parse_options, large_integers) = match options {
+    Some(opts) => {
+      let opts: JsValue = opts.into();
+      (parse_options_from_js(&opts), large_integers_from_js(&opts)?)
+    }
+    None => (ParseOptions::default(), numbers::LargeIntegers::default()),
+  };
+  // empty text converts to null like it did when parsing to a serde value
+  let value = text_to_js_value(text, &parse_options, large_integers)?;
+  Ok(value.unwrap_or(JsValue::NULL))
+}
+
+/// Converts JSONC text to a JavaScript value, or `None` when the text has no
+/// value.
+///
+/// This parses to an AST rather than a `serde_json::Value` so integers
+/// outside the range of an `i64` or `u64` keep their precision.
+fn text_to_js_value(
+  text: &str,
+  parse_options: &ParseOptions,
+  large_integers: numbers::LargeIntegers,
+) -> Result<Option<JsValue>, JsValue> {
+  let result =
+    jsonc_parser::parse_to_ast(text, &Default::default(), parse_options)
+      .map_err(|e| ParseError::new(&e, text))?;
+  let Some(value) = &result.value else {
+    return Ok(None);
+  };
+  // json_compatible gets plain JS objects instead of Maps
+  let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+  numbers::SerializableValue::new(value, large_integers)
+    .serialize(&serializer)
+    .map(Some)
+    .map_err(|e| throw_error(&format!("Failed to convert value: {}", e)))
+}
+
+/// Gets the value node a node converts to, which is a property's value for
+/// a property. Property names, tokens and trivia have no value.
+fn value_node(node: &JsoncCstNode) -> Option<JsoncCstNode> {
+  match node {
+    JsoncCstNode::Container(CstContainerNode::Root(root)) => root.value(),
+    JsoncCstNode::Container(CstContainerNode::ObjectProp(prop)) => prop.value(),
+    JsoncCstNode::Leaf(
+      CstLeafNode::Token(_)
+      | CstLeafNode::Whitespace(_)
+      | CstLeafNode::Newline(_)
+      | CstLeafNode::Comment(_),
+    ) => None,
+    _ => match node.parent() {
+      Some(CstContainerNode::ObjectProp(prop))
+        if prop
+          .value()
+          .is_none_or(|value| value.child_index() != node.child_index()) =>
+      {
+        None
+      }
+      _ => Some(node.clone()),
+    },
+  }
+}
+
+fn large_integers_from_js(
+  obj: &JsValue,
+) -> Result<numbers::LargeIntegers, JsValue> {
+  if !obj.is_object() {
+    return Ok(numbers::LargeIntegers::default());
+  }
+  let value = js_sys::Reflect::get(obj, &"largeIntegers".into())
+    .ok()
+    .filter(|v| !v.is_undefined());
+  let Some(value) = value else {
+    return Ok(numbers::LargeIntegers::default());
+  };
+  match value.as_string().as_deref() {
+    Some("number") => Ok(numbers::LargeIntegers::Number),
+    Some("bigint") => Ok(numbers::LargeIntegers::BigInt),
+    Some("string") => Ok(numbers::LargeIntegers::String),
+    _ => Err(throw_error(
+      "Expected largeIntegers to be \"number\", \"bigint\" or \"string\"",
+    )),
+  }
+}
+
+/// Formats JSONC text like dprint-plugin-json, keeping its comments.
+/// @param text - The JSONC text to format
+/// @param options - Optional formatting options
+/// @returns The formatted text
+/// @throws {ParseError} If the text cannot be parsed
+/// @throws If an option is invalid
+#[wasm_bindgen]
+pub fn format(
+  text: &str,
+  options: Option<FormatOptionsObject>,
+) -> Result<String, JsValue> {
+  parse(text, None)?.format(options)
+}
+
+/// A document or value compared by `diff`.
+///
+/// Documents are converted in Rust so their numbers keep the precision of
+/// the text rather than that of JavaScript numbers.
+#[wasm_bindgen]
+pub struct DiffInput {
+  value: serde_json::Value,
+}
+
+#[wasm_bindgen]
+impl DiffInput {
+  /// Compares the value of a document.
+  /// @param root - The document, whose value is `null` when it has none
+  /// @returns The input to pass to `diff`
+  #[wasm_bindgen(js_name = fromRoot)]
+  pub fn from_root(root: &RootNode) -> DiffInput {
+    let value = root
+      .inner
+      .value()
+      .and_then(|node| values::to_serde_value(&node))
+      .unwrap_or(serde_json::Value::Null);
+    DiffInput { value }
+  }
+
+  /// Compares a plain value.
+  /// @param value - The value
+  /// @returns The input to pass to `diff`
+  /// @throws If the value cannot be converted
+  #[wasm_bindgen(js_name = fromValue)]
+  pub fn from_value(value: JsonValue) -> Result<DiffInput, JsValue> {
+    let value = js_value_to_serde_value(&value)?;
+    Ok(DiffInput { value })
+  }
+}
+
+/// Computes the JSON Patch (RFC 6902) operations that transform one value into another.
+/// @param a - The original value
+/// @param b - The new value
+/// @param options - Optional diff options
+/// @returns The patch operations, which can be passed to `RootNode.applyPatch`
+#[wasm_bindgen]
+pub fn diff(
+  a: &DiffInput,
+  b: &DiffInput,
+  options: Option<DiffOptionsObject>,
+) -> Result<JsonPatchOperations, JsValue> {
+  let options = match options {
+    Some(opts) => diff_options_from_js(&opts.into()),
+    None => diff::DiffOptions::default(),
+  };
+  let operations = diff::diff(&a.value, &b.value, &options)
+    .iter()
+    .map(|operation| operation.to_value())
+    .collect::<Vec<_>>();
+  let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+  operations
+    .serialize(&serializer)
+    .map(|value| value.unchecked_into())
+    .map_err(|e| throw_error(&format!("Failed to convert value: {}", e)))
+}
+
+fn diff_options_from_js(obj: &JsValue) -> diff::DiffOptions {
+  let defaults = diff::DiffOptions::default();
+
+  if !obj.is_object() {
+    return defaults;
+  }
+
+  let detect_moves = js_sys::Reflect::get(obj, &"detectMoves".into())
+    .ok()
+    .and_then(|v| v.as_bool())
+    .unwrap_or(defaults.detect_moves);
+
+  diff::DiffOptions { detect_moves }
+}
+
+/// Validates a document against a JSON Schema (draft 2020-12), reporting
+/// where in the text each invalid value is.
+///
+/// Supports `type`, `enum`, `pattern`, `properties`, `patternProperties`,
+/// `additionalProperties`, `required`, `items`, `allOf`, `anyOf`, `oneOf` and
+/// `$ref` to a location in the same schema. Other keywords are ignored.
+/// @param root - The document to validate
+/// @param schema - The JSON Schema
+/// @returns The values that don't match the schema, which is empty when the document is valid
+/// @throws If the schema is invalid or references another document
+#[wasm_bindgen]
+pub fn validate(
+  root: &RootNode,
+  schema: JsonValue,
+) -> Result<ValidationErrors, JsValue> {
+  let schema = js_value_to_serde_value(&schema.into())?;
+  // patterns are ECMA-262 regular expressions, so JavaScript's are used
+  let compile_pattern = |pattern: &str| {
+    new_regex(pattern).map(|regex| {
+      Box::new(move |text: &str| regex.test(text)) as schema::Pattern
+    })
+  };
+  let errors = schema::validate(&root.inner, &schema, &compile_pattern)
+    .map_err(|e| throw_error(&e.to_string()))?;
+  Ok(to_js_object(&errors))
+}
+
+/// Creates a `RegExp` for the pattern, or gets why it's invalid.
+fn new_regex(pattern: &str) -> Result<js_sys::RegExp, String> {
+  let constructor = js_sys::RegExp::new("", "").constructor();
+  let args = js_sys::Array::of2(&pattern.into(), &"u".into());
+  js_sys::Reflect::construct(&constructor, &args)
+    .map(|regex| regex.unchecked_into())
+    .map_err(|err| match err.dyn_into::<js_sys::Error>() {
+      Ok(err) => String::from(err.message()),
+      Err(err) => format!("{:?}", err),
+    })
+}
+
+const MAX_INDENT_WIDTH: usize = 16;
+const MAX_LINE_WIDTH: usize = 100_000;
+const MAX_BLANK_LINES: usize = 1_000;
+
+/// Gets an integer option, which must be a whole number from 0 to `max`.
+fn integer_option(
+  value: &JsValue,
+  name: &str,
+  max: usize,
+) -> Result<usize, JsValue> {
+  match value.as_f64() {
+    Some(number)
+      if number.fract() == 0.0 && (0.0..=max as f64).contains(&number) =>
+    {
+      Ok(number as usize)
+    }
+    _ => Err(throw_error(&format!(
+      "Expected {} to be an integer from 0 to {}",
+      name, max
+    ))),
+  }
+}
+
+fn format_options_from_js(
+  mut options: format::FormatOptions,
+  obj: &JsValue,
+) -> Result<format::FormatOptions, JsValue> {
+  if !obj.is_object() {
+    return Ok(options);
+  }
+
+  let get = |name: &str| {
+    js_sys::Reflect::get(obj, &name.into())
+      .ok()
+      .filter(|v| !v.is_undefined())
+  };
+
+  let indent_width = get("indentWidth")
+    .map(|width| integer_option(&width, "indentWidth", MAX_INDENT_WIDTH))
+    .transpose()?;
+  if get("useTabs").and_then(|v| v.as_bool()) == Some(true) {
+    options.indent_text = "\t".to_string();
+    if let Some(width) = indent_width {
+      options.tab_width = width;
+    }
+  } else if let Some(width) = indent_width {
+    options.indent_text = " ".repeat(width);
+  }
+
+  if let Some(kind) = get("newlineKind") {
+    options.newline = match kind.as_string().as_deref() {
+      Some("\n") => "\n",
+      Some("\r\n") => "\r\n",
+      _ => {
+        return Err(throw_error(
+          "Expected newlineKind to be \"\\n\" or \"\\r\\n\"",
+        ));
+      }
+    };
+  }
+
+  if let Some(width) = get("lineWidth") {
+    options.line_width =
+      Some(integer_option(&width, "lineWidth", MAX_LINE_WIDTH)?);
+  }
+
+  if let Some(prefer) = get("preferSingleLine").and_then(|v| v.as_bool()) {
+    options.prefer_single_line = prefer;
+  }
+
+  if let Some(max) = get("maxBlankLines") {
+    options.max_blank_lines =
+      integer_option(&max, "maxBlankLines", MAX_BLANK_LINES)?;
+  }
+
+  if let Some(style) = get("quoteStyle") {
+    options.quote_style = match style.as_string().as_deref() {
+      Some("double") => format::QuoteStyle::Double,
+      Some("single") => format::QuoteStyle::Single,
+      Some("preserve") => format::QuoteStyle::Preserve,
+      _ => {
+        return Err(throw_error(
+          "Expected quoteStyle to be \"double\", \"single\" or \"preserve\"",
+        ));
+      }
+    };
+  }
+
+  options.trailing_commas = get("trailingCommas").and_then(|v| v.as_bool());
+
+  Ok(options)
+}
+
+fn comment_kind_from_js(
+  options: Option<CommentOptionsObject>,
+) -> Result<comments::CommentKind, JsValue> {
+  let Some(options) = options else {
+    return Ok(comments::CommentKind::Line);
+  };
+  let kind = js_sys::Reflect::get(&options, &"kind".into())
+    .ok()
+    .and_then(|v| v.as_string());
+  match kind.as_deref() {
+    None | Some("line") => Ok(comments::CommentKind::Line),
+    Some("block") => Ok(comments::CommentKind::Block),
+    Some(kind) => Err(throw_error(&format!(
+      "Expected comment kind to be \"line\" or \"block\", but found \"{}\"",
+      kind
+    ))),
+  }
+}
+
+fn parse_options_from_js(obj: &JsValue) -> ParseOptions {
+  let defaults = ParseOptions::default();
+
+  if !obj.is_object() {
+    return defaults;
+  }
+
+  let allow_comments = js_sys::Reflect::get(obj, &"allowComments".into())
+    .ok()
+    .and_then(|v| v.as_bool())
+    .unwrap_or(defaults.allow_comments);
+
+  let allow_trailing_commas =
+    js_sys::Reflect::get(obj, &"allowTrailingCommas".into())
+      .ok()
+      .and_then(|v| v.as_bool())
+      .unwrap_or(defaults.allow_trailing_commas);
+
+  let allow_loose_object_property_names =
+    js_sys::Reflect::get(obj, &"allowLooseObjectPropertyNames".into())
+      .ok()
+      .and_then(|v| v.as_bool())
+      .unwrap_or(defaults.allow_loose_object_property_names);
+
+  let allow_missing_commas =
+    js_sys::Reflect::get(obj, &"allowMissingCommas".into())
+      .ok()
+      .and_then(|v| v.as_bool())
+      .unwrap_or(defaults.allow_missing_commas);
+
+  let allow_single_quoted_strings =
+    js_sys::Reflect::get(obj, &"allowSingleQuotedStrings".into())
+      .ok()
+      .and_then(|v| v.as_bool())
+      .unwrap_or(defaults.allow_single_quoted_strings);
+
+  let allow_hexadecimal_numbers =
+    js_sys::Reflect::get(obj, &"allowHexadecimalNumbers".into())
+      .ok()
+      .and_then(|v| v.as_bool())
+      .unwrap_or(defaults.allow_hexadecimal_numbers);
+
+  let allow_unary_plus_numbers =
+    js_sys::Reflect::get(obj, &"allowUnaryPlusNumbers".into())
+      .ok()
+      .and_then(|v| v.as_bool())
+      .unwrap_or(defaults.allow_unary_plus_numbers);
+
+  ParseOptions {
+    allow_comments,
+    allow_trailing_commas,
+    allow_loose_object_property_names,
+    allow_missing_commas,
+    allow_single_quoted_strings,
+    allow_hexadecimal_numbers,
+    allow_unary_plus_numbers,
+  }
+}
+
+fn js_value_to_serde_value(
+  value: &JsValue,
+) -> Result<serde_json::Value, JsValue> {
+  serde_wasm_bindgen::from_value(value.clone())
+    .map_err(|e| throw_error(&format!("Failed to convert value: {}", e)))
+}
+
+fn js_value_to_cst_input(value: &JsValue) -> Result<CstInputValue, JsValue> {
+  // Convert JsValue to serde_json::Value using serde-wasm-bindgen
+  let serde_value = js_value_to_serde_value(value)?;
+
+  // Convert serde_json::Value to CstInputValue
+  Ok(values::from_serde_value(serde_value))
+}
+
+/// Converts a plain JS value to insert, or copies a node from this or
+/// another document.
+fn insert_value_from_js(
+  value: &JsValue,
+) -> Result<values::InsertValue, JsValue> {
+  match node_from_js(value) {
+    Some(node) => values::InsertValue::copy_of(&node)
+      .map_err(|e| throw_error(&e.to_string())),
+    None => Ok(js_value_to_serde_value(value)?.into()),
+  }
+}
+
+thread_local! {
+  static PASSED_NODE: RefCell<Option<JsoncCstNode>> =
+    const { RefCell::new(None) };
+}
+
+/// Stores the node of a wrapper for `node_from_js` to take.
+fn pass_node(node: JsoncCstNode) {
+  PASSED_NODE.with(|passed| *passed.borrow_mut() = Some(node));
+}
+
+/// Gets the node of a wrapper class passed where a node or a plain value is
+/// accepted.
+///
+/// wasm-bindgen can only convert a `JsValue` to a wrapper by taking it over,
+/// which would leave the caller's object unusable, so the wrapper's hidden
+/// `__passNode` method hands its node over instead.
+fn node_from_js(value: &JsValue) -> Option<JsoncCstNode> {
+  let pass = js_sys::Reflect::get(value, &JsValue::from_str("__passNode"))
+    .ok()?
+    .dyn_into::<js_sys::Function>()
+    .ok()?;
+  pass.call0(value).ok()?;
+  PASSED_NODE.with(|passed| passed.borrow_mut().take())
+}
+
+/// Implements the methods every node wrapper has, where `passable` also
+/// adds the hidden `__passNode` method for wrappers that can be passed where
+/// a node or a plain value is accepted.
+macro_rules! node_methods {
+  ($wrapper:ident) => {
+    #[wasm_bindgen]
+    impl $wrapper {
+      /// Returns the start and end byte offsets of this node in the document's text.
+      /// @returns The byte range of the node
+      #[wasm_bindgen(js_name = range)]
+      pub fn range(&self) -> TextRangeObject {
+        to_js_object(&position::node_range(&self.inner.clone().into()))
+      }
+
+      /// Returns the start and end offsets of this node in UTF-16 code units,
+      /// which can be used directly with JavaScript string indexes.
+      /// @returns The UTF-16 range of the node
+      #[wasm_bindgen(js_name = utf16Range)]
+      pub fn utf16_range(&self) -> TextRangeObject {
+        to_js_object(&position::node_utf16_range(&self.inner.clone().into()))
+      }
+
+      /// Returns the 1-based line and column where this node starts.
+      /// @returns The start line and column
+      #[wasm_bindgen(js_name = startLineColumn)]
+      pub fn start_line_column(&self) -> LineColumnObject {
+        to_js_object(&position::node_start_line_column(
+          &self.inner.clone().into(),
+        ))
+      }
+
+      /// Returns the 1-based line and column where this node ends.
+      /// @returns The end line and column
+      #[wasm_bindgen(js_name = endLineColumn)]
+      pub fn end_line_column(&self) -> LineColumnObject {
+        to_js_object(&position::node_end_line_column(
+          &self.inner.clone().into(),
+        ))
+      }
+
+      /// Returns if this node is part of a document, which is always true
+      /// for the root node. Other nodes are detached once they or one of
+      /// their ancestors are removed or replaced, and can no longer be
+      /// modified.
+      /// @returns True if the node is attached to a document
+      #[wasm_bindgen(js_name = isAttached)]
+      pub fn is_attached(&self) -> bool {
+        values::is_attached(&self.inner.clone().into())
+      }
+    }
+  };
+  ($wrapper:ident, passable) => {
+    node_methods!($wrapper);
+
+    #[wasm_bindgen]
+    impl $wrapper {
+      /// Hands this node to `node_from_js`.
+      #[wasm_bindgen(js_name = __passNode, skip_typescript)]
+      pub fn pass_node(&self) {
+        pass_node(self.inner.clone().into());
+      }
+    }
+  };
+}
+
+node_methods!(RootNode);
+node_methods!(Node, passable);
+node_methods!(JsonObject, passable);
+node_methods!(ObjectPropName);
+node_methods!(ObjectProp, passable);
+node_methods!(JsonArray, passable);
+node_methods!(StringLit, passable);
+node_methods!(NumberLit, passable);
+node_methods!(BooleanLit, passable);
+node_methods!(NullKeyword, passable);
+node_methods!(WordLit, passable);
+
+/// Parses the JSONC text of a value to insert into the target's document.
+fn parse_insert_value(
+  text: &str,
+  options: Option<JsoncParseOptionsObject>,
+  target: &JsoncCstNode,
+) -> Result<values::InsertValue, JsValue> {
+  let parse_options = match options {
+    Some(opts) => parse_options_from_js(&opts.into()),
+    None => target
+      .root_node()
+      .map(|root| root.parse_options().clone())
+      .unwrap_or_default(),
+  };
+  values::InsertValue::parse(text, &parse_options).map_err(|err| match err {
+    values::InsertValueError::Parse(e) => ParseError::new(&e, text).into(),
+    err => throw_error(&err.to_string()),
+  })
+}
+
+thread_local! {
+  static LF: JsString = JsString::from("\n");
+  static CRLF: JsString = JsString::from("\r\n");
+}
+
+/// Represents the root node of a JSONC document.
+/// This is the entry point for manipulating the concrete syntax tree.
+#[wasm_bindgen]
+pub struct RootNode {
+  inner: cst::CstRootNode,
+}
+
+impl RootNode {
+  /// Creates the error for when the root value isn't the expected kind.
+  fn wrong_value_kind(
+    &self,
+    code: ErrorCode,
+    message: &str,
+    expected: &'static str,
+  ) -> JsValue {
+    let value = self.inner.value();
+    let node = value.clone().unwrap_or_else(|| self.inner.clone().into());
+    JsoncMorphError::new(code, message)
+      .kinds(expected, value.as_ref())
+      .at(&node)
+      .into()
+  }
+}
+
+#[wasm_bindgen]
+impl RootNode {
+  /// Returns the root value node.
+  /// @returns The root value, or undefined if the document is empty
+  #[wasm_bindgen(js_name = value)]
+  pub fn value(&self) -> Option<Node> {
+    self.inner.value().map(Node::new)
+  }
+
+  /// Returns the root value node, throwing if empty.
+  /// @returns The root value
+  /// @throws {JsoncMorphError} If the document is empty
+  #[wasm_bindgen(js_name = valueOrThrow)]
+  pub fn value_or_throw(&self) -> Result<Node, JsValue> {
+    self.value().ok_or_else(|| {
+      JsoncMorphError::new(
+        ErrorCode::ExpectedValue,
+        "Expected a value, but found none",
+      )
+      .at(&self.inner.clone().into())
+      .into()
+    })
+  }
+
+  /// Returns the root value as an object if it is one.
+  /// @returns The object, or undefined if root is not an object
+  #[wasm_bindgen(js_name = asObject)]
+  pub fn as_object(&self) -> Option<JsonObject> {
+    self.inner.object_value().map(JsonObject::new)
+  }
+
+  /// Returns the root value as an object, throwing if it's not an object.
+  /// @returns The object
+  /// @throws {JsoncMorphError} If the root is not an object
+  #[wasm_bindgen(js_name = asObjectOrThrow)]
+  pub fn as_object_or_throw(&self) -> Result<JsonObject, JsValue> {
+    self.as_object().ok_or_else(|| {
+      self.wrong_value_kind(
+        ErrorCode::ExpectedObject,
+        "Expected an object value, but found a different type",
+        "object",
+      )
+    })
+  }
+
+  /// Returns the root value as an object, creating an empty object if the root is empty.
+  /// Returns undefined if the root contains a value of a different type.
+  /// @returns The object, or undefined if a non-object value exists
+  #[wasm_bindgen(js_name = asObjectOrCreate)]
+  pub fn as_object_or_create(&self) -> Option<JsonObject> {
+    self.inner.object_value_or_create().map(JsonObject::new)
+  }
+
+  /// Returns the root value as an object, replacing any existing value with an empty object if needed.
+  /// Unlike asObjectOrCreate, this always returns an object by replacing non-object values.
+  /// @returns The object (always succeeds)
+  #[wasm_bindgen(js_name = asObjectOrForce)]
+  pub fn as_object_or_force(&self) -> JsonObject {
+    JsonObject::new(self.inner.object_value_or_set())
+  }
+
+  /// Returns the root value as an array if it is one.
+  /// @returns The array, or undefined if root is not an array
+  #[wasm_bindgen(js_name = asArray)]
+  pub fn as_array(&self) -> Option<JsonArray> {
+    self.inner.array_value().map(JsonArray::new)
+  }
+
+  /// Returns the root value as an array, throwing if it's not an array.
+  /// @returns The array
+  /// @throws {JsoncMorphError} If the root is not an array
+  #[wasm_bindgen(js_name = asArrayOrThrow)]
+  pub fn as_array_or_throw(&self) -> Result<JsonArray, JsValue> {
+    self.as_array().ok_or_else(|| {
+      self.wrong_value_kind(
+        ErrorCode::ExpectedArray,
+        "Expected an array value, but found a different type",
+        "array",
+      )
+    })
+  }
+
+  /// Returns the root value as an array, creating an empty array if the root is empty.
+  /// Returns undefined if the root contains a value of a different type.
+  /// @returns The array, or undefined if a non-array value exists
+  #[wasm_bindgen(js_name = asArrayOrCreate)]
+  pub fn as_array_or_create(&self) -> Option<JsonArray> {
+    self.inner.array_value_or_create().map(JsonArray::new)
+  }
+
+  /// Returns the root value as an array, replacing any existing value with an empty array if needed.
+  /// Unlike asArrayOrCreate, this always returns an array by replacing non-array values.
+  /// @returns The array (always succeeds)
+  #[wasm_bindgen(js_name = asArrayOrForce)]
+  pub fn as_array_or_force(&self) -> JsonArray {
+    JsonArray::new(self.inner.array_value_or_set())
+  }
+
+  /// Converts the CST back to a string representation.
+  /// When options are provided, only those options are applied and the rest of the
+  /// document's style is kept. Indentation, newline, quote style and trailing comma
+  /// options don't move anything to another line. The line width, single line and
+  /// blank line options reflow the document with the formatter, keeping its comments.
+  /// The CST itself isn't changed.
+  /// @param options - Optional formatting options
+  /// @returns The JSONC string
+  /// @throws If an option is invalid
+  #[wasm_bindgen(js_name = toString)]
+  pub fn to_string_output(
+    &self,
+    options: Option<FormatOptionsObject>,
+  ) -> Result<String, JsValue> {
+    let Some(options) = options else {
+      return Ok(self.inner.to_string());
+    };
+    let options: JsValue = options.into();
+    let defaults = format::FormatOptions::for_root(&self.inner);
+    let format_options = format_options_from_js(defaults, &options)?;
+    let reflows = ["lineWidth", "preferSingleLine", "maxBlankLines"]
+      .iter()
+      .any(|name| {
+        js_sys::Reflect::get(&options, &(*name).into())
+          .is_ok_and(|v| !v.is_undefined())
+      });
+    if reflows {
+      return Ok(format::format(&self.inner, &format_options));
+    }
+    // apply the options to a copy so the CST isn't changed
+    let text = self.inner.to_string();
+    let copy = cst::CstRootNode::parse(&text, self.inner.parse_options())
+      .map_err(|e| ParseError::new(&e, &text))?;
+    format::restyle(&copy, &format_options);
+    Ok(copy.to_string())
+  }
+
+  /// Formats the whole document like dprint-plugin-json, keeping its comments.
+  /// Unlike `toString(options)`, unspecified options use the formatter's
+  /// defaults (2 space indentation and a line width of 120) rather than the
+  /// document's current style. The CST itself isn't changed.
+  /// @param options - Optional formatting options
+  /// @returns The formatted JSONC string
+  /// @throws If an option is invalid
+  #[wasm_bindgen(js_name = format)]
+  pub fn format(
+    &self,
+    options: Option<FormatOptionsObject>,
+  ) -> Result<String, JsValue> {
+    let defaults = format::FormatOptions::dprint(&self.inner);
+    let options = match options {
+      Some(options) => format_options_from_js(defaults, &options.into())?,
+      None => defaults,
+    };
+    Ok(format::format(&self.inner, &options))
+  }
+
+  /// Returns all child nodes including whitespace and punctuation.
+  /// @returns Array of all child nodes
+  #[wasm_bindgen(js_name = children)]
+  pub fn children(&self) -> Vec<Node> {
+    self.inner.children().into_iter().map(Node::new).collect()
+  }
+
+  /// Returns the deepest node that contains the byte offset.
+  /// When the offset is between two nodes, the node starting at the offset is returned.
+  /// @param offset - The byte offset in the document's text
+  /// @returns The node, or undefined if the offset is outside the document
+  #[wasm_bindgen(js_name = nodeAtOffset)]
+  pub fn node_at_offset(&self, offset: usize) -> Option<Node> {
+    position::node_at_offset(&self.inner.clone().into(), offset).map(Node::new)
+  }
+
+  /// Returns the deepest node at the 1-based line and column.
+  /// Columns are measured in UTF-16 code units, like the positions reported by editors.
+  /// @param line - The 1-based line number
+  /// @param column - The 1-based column number
+  /// @returns The node, or undefined if the position is outside the document
+  #[wasm_bindgen(js_name = nodeAtLineColumn)]
+  pub fn node_at_line_column(
+    &self,
+    line: usize,
+    column: usize,
+  ) -> Option<Node> {
+    let text = self.inner.to_string();
+    let offset = position::byte_offset_at_line_column(&text, line, column)?;
+    self.node_at_offset(offset)
+  }
+
+  /// Returns the outermost nodes that overlap the byte range.
+  /// Nodes entirely within the range are returned as a whole, while partially
+  /// overlapping containers are descended into.
+  /// @param start - The start byte offset (inclusive)
+  /// @param end - The end byte offset (exclusive)
+  /// @returns The nodes in document order
+  #[wasm_bindgen(js_name = nodesInRange)]
+  pub fn nodes_in_range(&self, start: usize, end: usize) -> Vec<Node> {
+    let range = position::TextRange { start, end };
+    position::nodes_in_range(&self.inner.clone().into(), range)
+      .into_iter()
+      .map(Node::new)
+      .collect()
+  }
+
+  /// Returns the value at the JSON Pointer (RFC 6901).
+  /// @param pointer - The JSON Pointer (ex. "/compilerOptions/paths/~1foo/0")
+  /// @returns The value, or undefined if nothing exists at the pointer
+  /// @throws If the pointer is invalid
+  #[wasm_bindgen(js_name = getPointer)]
+  pub fn get_pointer(&self, pointer: &str) -> Result<Option<Node>, JsValue> {
+    pointer::get(&self.inner, pointer)
+      .map(|n| n.map(Node::new))
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Sets the value at the JSON Pointer (RFC 6901).
+  /// Existing values are replaced and missing object properties are added, including
+  /// any missing intermediate objects. For arrays, the last token may be an existing
+  /// index, the array's length, or "-" to append.
+  /// @param pointer - The JSON Pointer
+  /// @param value - The value to set
+  /// @throws If the pointer is invalid or can't be set
+  #[wasm_bindgen(js_name = setPointer)]
+  pub fn set_pointer(
+    &self,
+    pointer: &str,
+    value: JsValue,
+  ) -> Result<(), JsValue> {
+    let value = js_value_to_serde_value(&value)?;
+    pointer::set(&self.inner, pointer, value)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Removes the value at the JSON Pointer (RFC 6901).
+  /// Values in objects are removed along with their property.
+  /// @param pointer - The JSON Pointer
+  /// @returns True if a value was removed, false if nothing exists at the pointer
+  /// @throws If the pointer is invalid
+  #[wasm_bindgen(js_name = removePointer)]
+  pub fn remove_pointer(&self, pointer: &str) -> Result<bool, JsValue> {
+    pointer::remove(&self.inner, pointer)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Applies a JSON Patch (RFC 6902) to the document.
+  /// Edits are made in place, so comments and formatting outside the changed values are kept.
+  /// The patch is applied atomically: if any operation fails, the document is left unchanged.
+  /// Values that are moved or copied keep the comments within them, and moved values also keep the comments around them.
+  /// @param operations - The patch operations
+  /// @throws If an operation is invalid, a path doesn't exist, or a test fails
+  #[wasm_bindgen(js_name = applyPatch)]
+  pub fn apply_patch(
+    &self,
+    operations: JsonPatchOperations,
+  ) -> Result<(), JsValue> {
+    let values: Vec<serde_json::Value> =
+      serde_wasm_bindgen::from_value(operations.into()).map_err(|e| {
+        throw_error(&format!("Failed to convert operations: {}", e))
+      })?;
+    let operations = patch::parse_operations(&values)
+      .map_err(|e| throw_error(&e.to_string()))?;
+    patch::apply(&self.inner, &operations)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Applies a JSON Merge Patch (RFC 7396) to the document.
+  /// Properties set to null are removed, objects are merged recursively, and other
+  /// values are replaced. Untouched properties keep their comments and formatting.
+  /// A patch that is not an object replaces the entire value.
+  /// @param patch - The merge patch
+  #[wasm_bindgen(js_name = mergePatch)]
+  pub fn merge_patch(&self, patch: JsValue) -> Result<(), JsValue> {
+    let patch = js_value_to_serde_value(&patch)?;
+    merge::merge_into_root(&self.inner, &patch);
+    Ok(())
+  }
+
+  /// Computes the JSON Pointer (RFC 6901) of a node within its document.
+  /// Properties and property names resolve to the pointer of the property's value, while
+  /// whitespace, comments, and tokens resolve to the pointer of the value containing them.
+  /// @param node - The node to get the pointer of
+  /// @returns The JSON Pointer, or undefined if the node is not in this document
+  #[wasm_bindgen(js_name = pointerOf)]
+  pub fn pointer_of(&self, node: &Node) -> Option<String> {
+    pointer::pointer_of(&self.inner, &node.inner)
+  }
+
+  /// Sets the root value of the document.
+  /// Accepts any JSON value: string, number, boolean, null, array, or object.
+  /// @param value - The new value to set
+  #[wasm_bindgen(js_name = setValue)]
+  pub fn set_value(&self, value: JsValue) -> Result<(), JsValue> {
+    let cst_input = js_value_to_cst_input(&value)?;
+    self.inner.set_value(cst_input);
+    Ok(())
+  }
+
+  /// Configures whether trailing commas should be used throughout the document.
+  /// When enabled, trailing commas are added for multiline formatting in objects and arrays.
+  /// @param enabled - Whether to enable trailing commas
+  #[wasm_bindgen(js_name = setTrailingCommas)]
+  pub fn set_trailing_commas(&self, enabled: bool) {
+    use jsonc_parser::cst::TrailingCommaMode;
+    let mode = if enabled {
+      TrailingCommaMode::IfMultiline
+    } else {
+      TrailingCommaMode::Never
+    };
+    self.inner.set_trailing_commas(mode);
+  }
+
+  /// Clears all children from the root node, leaving an empty document.
+  #[wasm_bindgen(js_name = clearChildren)]
+  pub fn clear_children(&self) {
+    self.inner.clear_children();
+  }
+
+  /// Returns the indentation string used for a single level.
+  /// @returns The single-level indentation string (e.g., "  " or "\t")
+  #[wasm_bindgen(js_name = singleIndentText)]
+  pub fn single_indent_text(&self) -> Option<String> {
+    self.inner.single_indent_text()
+  }
+
+  /// Returns the newline kind used in the document.
+  /// @returns Either "\n" or "\r\n"
+  #[wasm_bindgen(js_name = newlineKind)]
+  pub fn newline_kind(&self) -> JsString {
+    match self.inner.newline_kind() {
+      cst::CstNewlineKind::LineFeed => LF.with(|s| s.clone()),
+      cst::CstNewlineKind::CarriageReturnLineFeed => CRLF.with(|s| s.clone()),
+    }
+  }
+
+  /// Changes every newline in the document to the newline kind, including
+  /// those within block comments. Nothing else is reformatted.
+  /// @param kind - Either "\n" or "\r\n"
+  /// @throws If the newline kind is invalid
+  #[wasm_bindgen(js_name = setNewlineKind)]
+  pub fn set_newline_kind(&self, kind: &str) -> Result<(), JsValue> {
+    let kind = match kind {
+      "\n" => cst::CstNewlineKind::LineFeed,
+      "\r\n" => cst::CstNewlineKind::CarriageReturnLineFeed,
+      _ => {
+        return Err(throw_error(
+          "Expected the newline kind to be \"\\n\" or \"\\r\\n\"",
+        ));
+      }
+    };
+    trivia::set_newline_kind(&self.inner, kind);
+    Ok(())
+  }
+
+  /// Changes the text of a single level of indentation, re-indenting every
+  /// line that starts with the document's current indentation (see
+  /// `singleIndentText()`). Nothing else is reformatted.
+  /// @param text - The indentation text, which may only contain spaces or tabs
+  /// @returns Whether the document had indentation to change
+  /// @throws If the text contains other characters
+  #[wasm_bindgen(js_name = setIndentText)]
+  pub fn set_indent_text(&self, text: &str) -> Result<bool, JsValue> {
+    if text.is_empty() || !text.chars().all(|c| c == ' ' || c == '\t') {
+      return Err(throw_error(
+        "Expected the indentation text to only contain spaces or tabs",
+      ));
+    }
+    Ok(trivia::set_indent_text(&self.inner, text))
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+
+  /// Returns child nodes excluding whitespace, comments, and punctuation.
+  /// @returns Array of significant child nodes
+  #[wasm_bindgen(js_name = childrenExcludeTriviaAndTokens)]
+  pub fn children_exclude_trivia_and_tokens(&self) -> Vec<Node> {
+    self
+      .inner
+      .children_exclude_trivia_and_tokens()
+      .into_iter()
+      .map(Node::new)
+      .collect()
+  }
+
+  /// Returns the child node at the specified index.
+  /// @param index - The child index
+  /// @returns The child node, or undefined if index is out of bounds
+  #[wasm_bindgen(js_name = childAtIndex)]
+  pub fn child_at_index(&self, index: usize) -> Option<Node> {
+    self.inner.child_at_index(index).map(Node::new)
+  }
+
+  /// Converts the CST to a plain JavaScript value, similar to JSON.parse.
+  /// This recursively converts the root value to its JavaScript equivalent.
+  /// Comments and formatting information are discarded.
+  /// @param options - Optional conversion options
+  /// @returns The plain JavaScript value (object, array, string, number, boolean, or null)
+  /// @throws If an option is invalid
+  #[wasm_bindgen(js_name = toValue)]
+  pub fn to_value(
+    &self,
+    options: Option<ToValueOptionsObject>,
+  ) -> Result<JsValue, JsValue> {
+    if let Some(value_node) = self.value() {
+      value_node.to_value(options)
+    } else {
+      Ok(JsValue::UNDEFINED)
+    }
+  }
+
+  /// Returns the comments within this node that aren't leading or trailing
+  /// comments of its children (ex. comments in an empty object).
+  /// @returns The inner comments
+  #[wasm_bindgen(js_name = innerComments)]
+  pub fn inner_comments(&self) -> CommentInfos {
+    comment_infos(comments::inner(&self.inner.clone().into()))
+  }
+
+  /// Replaces the leading comments of the document's value with a single
+  /// comment, or adds one when there are none.
+  /// @param text - The text of the comment, without delimiters
+  /// @param options - Optional comment options
+  /// @throws If the text is invalid
+  #[wasm_bindgen(js_name = setLeadingComment)]
+  pub fn set_leading_comment(
+    &self,
+    text: &str,
+    options: Option<CommentOptionsObject>,
+  ) -> Result<(), JsValue> {
+    let kind = comment_kind_from_js(options)?;
+    let value = self.value_or_throw()?;
+    comments::set_leading(&value.inner, text, kind)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Replaces the trailing comments of the document's value with a single
+  /// comment, or adds one when there are none.
+  /// @param text - The text of the comment, without delimiters
+  /// @param options - Optional comment options
+  /// @throws If the text is invalid
+  #[wasm_bindgen(js_name = setTrailingComment)]
+  pub fn set_trailing_comment(
+    &self,
+    text: &str,
+    options: Option<CommentOptionsObject>,
+  ) -> Result<(), JsValue> {
+    let kind = comment_kind_from_js(options)?;
+    let value = self.value_or_throw()?;
+    comments::set_trailing(&value.inner, text, kind)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Removes the comments outside of the document's value.
+  #[wasm_bindgen(js_name = removeComments)]
+  pub fn remove_comments(&self) {
+    for child in self.inner.children() {
+      if let Some(comment) = child.as_comment() {
+        comments::remove(comment);
+      }
+    }
+  }
+}
+
+/// Represents a generic node in the CST.
+/// Can be a container node (object, array, property) or a leaf node (string, number, boolean, null).
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Node {
+  inner: JsoncCstNode,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl Node {
+  fn new(inner: JsoncCstNode) -> Self {
+    Self {
+      _ancestors: ancestors(&inner),
+      inner,
+    }
+  }
+
+  /// Creates the error for when this node isn't the expected kind.
+  fn wrong_kind(
+    &self,
+    code: ErrorCode,
+    message: &str,
+    expected: &'static str,
+  ) -> JsValue {
+    JsoncMorphError::new(code, message)
+      .kinds(expected, Some(&self.inner))
+      .at(&self.inner)
+      .into()
+  }
+}
+
+#[wasm_bindgen]
+impl Node {
+  /// Removes this node from its parent.
+  /// Works for any node kind, including whitespace, comments, tokens, containers, and leaf values.
+  /// After calling this method, the node is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner)?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Creates a copy of this node that isn't part of the document, including
+  /// its comments and formatting. The copy can be inserted into this or
+  /// another document with `append` or `insert`.
+  /// @returns The copied node
+  /// @throws If the node can't be copied
+  #[wasm_bindgen(js_name = clone)]
+  pub fn clone_node(&self) -> Result<Node, JsValue> {
+    values::detached_copy(&self.inner)
+      .map(Node::new)
+      .ok_or_else(|| throw_error("Failed to copy the node"))
+  }
+
+  /// Moves this array element or property to the index within its container,
+  /// along with its comments and the formatting within it.
+  /// @param index - The new index
+  /// @returns This node
+  /// @throws If this isn't an array element or property, or the index is out
+  /// of bounds
+  #[wasm_bindgen(js_name = moveTo)]
+  pub fn move_to(&self, index: usize) -> Result<Node, JsValue> {
+    ensure_attached(&self.inner)?;
+    let result = match self.inner.as_object_prop() {
+      Some(prop) => reorder::move_property(&prop, index).map(|p| p.into()),
+      None => reorder::move_element(&self.inner, index),
+    };
+    result
+      .map(Node::new)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Returns true if this node is a container (object, array, or property).
+  /// @returns true if this is a container node
+  #[wasm_bindgen(js_name = isContainer)]
+  pub fn is_container(&self) -> bool {
+    matches!(self.inner, JsoncCstNode::Container(_))
+  }
+
+  /// Returns true if this node is a leaf value (string, number, boolean, null).
+  /// @returns true if this is a leaf node
+  #[wasm_bindgen(js_name = isLeaf)]
+  pub fn is_leaf(&self) -> bool {
+    matches!(self.inner, JsoncCstNode::Leaf(_))
+  }
+
+  /// Converts this node to an object if it is one.
+  /// @returns The object, or undefined if this node is not an object
+  #[wasm_bindgen(js_name = asObject)]
+  pub fn as_object(&self) -> Option<JsonObject> {
+    match &self.inner {
+      JsoncCstNode::Container(CstContainerNode::Object(obj)) => {
+        Some(JsonObject::new(obj.clone()))
+      }
+      _ => None,
+    }
+  }
+
+  /// Converts this node to an object, throwing if it's not an object.
+  /// @returns The object
+  /// @throws {JsoncMorphError} If this node is not an object
+  #[wasm_bindgen(js_name = asObjectOrThrow)]
+  pub fn as_object_or_throw(&self) -> Result<JsonObject, JsValue> {
+    self.as_object().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::ExpectedObject,
+        "Expected an object node, but found a different type",
+        "object",
+      )
+    })
+  }
+
+  /// Converts this node to an array if it is one.
+  /// @returns The array, or undefined if this node is not an array
+  #[wasm_bindgen(js_name = asArray)]
+  pub fn as_array(&self) -> Option<JsonArray> {
+    match &self.inner {
+      JsoncCstNode::Container(CstContainerNode::Array(arr)) => {
+        Some(JsonArray::new(arr.clone()))
+      }
+      _ => None,
+    }
+  }
+
+  /// Converts this node to an array, throwing if it's not an array.
+  /// @returns The array
+  /// @throws {JsoncMorphError} If this node is not an array
+  #[wasm_bindgen(js_name = asArrayOrThrow)]
+  pub fn as_array_or_throw(&self) -> Result<JsonArray, JsValue> {
+    self.as_array().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::ExpectedArray,
+        "Expected an array node, but found a different type",
+        "array",
+      )
+    })
+  }
+
+  /// Converts this node to the root node if it is one.
+  /// @returns The root node, or undefined if this is not a root node
+  #[wasm_bindgen(js_name = asRootNode)]
+  pub fn as_root_node(&self) -> Option<RootNode> {
+    self.inner.as_root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Converts this node to the root node, throwing if it's not a root node.
+  /// @returns The root node
+  /// @throws {JsoncMorphError} If this node is not a root node
+  #[wasm_bindgen(js_name = asRootNodeOrThrow)]
+  pub fn as_root_node_or_throw(&self) -> Result<RootNode, JsValue> {
+    self.as_root_node().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a root node, but found a different type",
+        "root",
+      )
+    })
+  }
+
+  /// Returns the decoded string value if this node is a string literal.
+  /// @returns The string value, or undefined if this node is not a string
+  #[wasm_bindgen(js_name = asString)]
+  pub fn as_string(&self) -> Option<String> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::StringLit(s)) => s.decoded_value().ok(),
+      _ => None,
+    }
+  }
+
+  /// Returns the decoded string value, throwing if not a string.
+  /// @returns The string value
+  /// @throws {JsoncMorphError} If this node is not a string
+  #[wasm_bindgen(js_name = asStringOrThrow)]
+  pub fn as_string_or_throw(&self) -> Result<String, JsValue> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::StringLit(s)) => {
+        s.decoded_value().map_err(|e| {
+          JsoncMorphError::new(
+            ErrorCode::InvalidString,
+            format!("Failed to decode string: {}", e),
+          )
+          .at(&self.inner)
+          .into()
+        })
+      }
+      _ => Err(self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a string node, but found a different type",
+        "string",
+      )),
+    }
+  }
+
+  /// Returns the raw string representation of a number literal.
+  /// Returns a string to preserve the exact formatting (e.g., "1.0" vs "1", "1e10" vs "10000000000").
+  /// @returns The number as a string, or undefined if this node is not a number
+  #[wasm_bindgen(js_name = numberValue)]
+  pub fn number_value(&self) -> Option<String> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::NumberLit(n)) => Some(n.to_string()),
+      _ => None,
+    }
+  }
+
+  /// Returns the raw string representation of a number literal, throwing if not a number.
+  /// Returns a string to preserve the exact formatting (e.g., "1.0" vs "1", "1e10" vs "10000000000").
+  /// @returns The number as a string
+  /// @throws {JsoncMorphError} If this node is not a number
+  #[wasm_bindgen(js_name = numberValueOrThrow)]
+  pub fn number_value_or_throw(&self) -> Result<String, JsValue> {
+    self.number_value().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a number node, but found a different type",
+        "number",
+      )
+    })
+  }
+
+  /// Returns the boolean value if this node is a boolean literal.
+  /// @returns The boolean value, or undefined if this node is not a boolean
+  #[wasm_bindgen(js_name = asBoolean)]
+  pub fn as_boolean(&self) -> Option<bool> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::BooleanLit(b)) => Some(b.value()),
+      _ => None,
+    }
+  }
+
+  /// Returns the boolean value, throwing if not a boolean.
+  /// @returns The boolean value
+  /// @throws {JsoncMorphError} If this node is not a boolean
+  #[wasm_bindgen(js_name = asBooleanOrThrow)]
+  pub fn as_boolean_or_throw(&self) -> Result<bool, JsValue> {
+    self.as_boolean().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a boolean node, but found a different type",
+        "boolean",
+      )
+    })
+  }
+
+  /// Returns true if this node is a null keyword.
+  /// @returns true if this node represents null
+  #[wasm_bindgen(js_name = isNull)]
+  pub fn is_null(&self) -> bool {
+    matches!(self.inner, JsoncCstNode::Leaf(CstLeafNode::NullKeyword(_)))
+  }
+
+  /// Returns true if this node is a string literal.
+  /// @returns true if this node is a string
+  #[wasm_bindgen(js_name = isString)]
+  pub fn is_string(&self) -> bool {
+    matches!(self.inner, JsoncCstNode::Leaf(CstLeafNode::StringLit(_)))
+  }
+
+  /// Returns true if this node is a number literal.
+  /// @returns true if this node is a number
+  #[wasm_bindgen(js_name = isNumber)]
+  pub fn is_number(&self) -> bool {
+    matches!(self.inner, JsoncCstNode::Leaf(CstLeafNode::NumberLit(_)))
+  }
+
+  /// Returns true if this node is a boolean literal.
+  /// @returns true if this node is a boolean
+  #[wasm_bindgen(js_name = isBoolean)]
+  pub fn is_boolean(&self) -> bool {
+    matches!(self.inner, JsoncCstNode::Leaf(CstLeafNode::BooleanLit(_)))
+  }
+
+  /// Returns this node as a StringLit if it is one.
+  /// @returns The StringLit, or undefined if this node is not a string literal
+  #[wasm_bindgen(js_name = asStringLit)]
+  pub fn as_string_lit(&self) -> Option<StringLit> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::StringLit(s)) => {
+        Some(StringLit::new(s.clone()))
+      }
+      _ => None,
+    }
+  }
+
+  /// Returns this node as a StringLit, throwing if it's not a string literal.
+  /// @returns The StringLit
+  /// @throws {JsoncMorphError} If this node is not a string literal
+  #[wasm_bindgen(js_name = asStringLitOrThrow)]
+  pub fn as_string_lit_or_throw(&self) -> Result<StringLit, JsValue> {
+    self.as_string_lit().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a string literal node, but found a different type",
+        "string",
+      )
+    })
+  }
+
+  /// Returns this node as a NumberLit if it is one.
+  /// @returns The NumberLit, or undefined if this node is not a number literal
+  #[wasm_bindgen(js_name = asNumberLit)]
+  pub fn as_number_lit(&self) -> Option<NumberLit> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::NumberLit(n)) => {
+        Some(NumberLit::new(n.clone()))
+      }
+      _ => None,
+    }
+  }
+
+  /// Returns this node as a NumberLit, throwing if it's not a number literal.
+  /// @returns The NumberLit
+  /// @throws {JsoncMorphError} If this node is not a number literal
+  #[wasm_bindgen(js_name = asNumberLitOrThrow)]
+  pub fn as_number_lit_or_throw(&self) -> Result<NumberLit, JsValue> {
+    self.as_number_lit().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a number literal node, but found a different type",
+        "number",
+      )
+    })
+  }
+
+  /// Returns this node as a BooleanLit if it is one.
+  /// @returns The BooleanLit, or undefined if this node is not a boolean literal
+  #[wasm_bindgen(js_name = asBooleanLit)]
+  pub fn as_boolean_lit(&self) -> Option<BooleanLit> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::BooleanLit(b)) => {
+        Some(BooleanLit::new(b.clone()))
+      }
+      _ => None,
+    }
+  }
+
+  /// Returns this node as a BooleanLit, throwing if it's not a boolean literal.
+  /// @returns The BooleanLit
+  /// @throws {JsoncMorphError} If this node is not a boolean literal
+  #[wasm_bindgen(js_name = asBooleanLitOrThrow)]
+  pub fn as_boolean_lit_or_throw(&self) -> Result<BooleanLit, JsValue> {
+    self.as_boolean_lit().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a boolean literal node, but found a different type",
+        "boolean",
+      )
+    })
+  }
+
+  /// Returns this node as a NullKeyword if it is one.
+  /// @returns The NullKeyword, or undefined if this node is not a null keyword
+  #[wasm_bindgen(js_name = asNullKeyword)]
+  pub fn as_null_keyword(&self) -> Option<NullKeyword> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::NullKeyword(n)) => {
+        Some(NullKeyword::new(n.clone()))
+      }
+      _ => None,
+    }
+  }
+
+  /// Returns this node as a NullKeyword, throwing if it's not a null keyword.
+  /// @returns The NullKeyword
+  /// @throws {JsoncMorphError} If this node is not a null keyword
+  #[wasm_bindgen(js_name = asNullKeywordOrThrow)]
+  pub fn as_null_keyword_or_throw(&self) -> Result<NullKeyword, JsValue> {
+    self.as_null_keyword().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a null keyword node, but found a different type",
+        "null",
+      )
+    })
+  }
+
+  /// Returns this node as a WordLit if it is one.
+  /// @returns The WordLit, or undefined if this node is not a word literal
+  #[wasm_bindgen(js_name = asWordLit)]
+  pub fn as_word_lit(&self) -> Option<WordLit> {
+    match &self.inner {
+      JsoncCstNode::Leaf(CstLeafNode::WordLit(w)) => {
+        Some(WordLit::new(w.clone()))
+      }
+      _ => None,
+    }
+  }
+
+  /// Returns this node as a WordLit, throwing if it's not a word literal.
+  /// @returns The WordLit
+  /// @throws {JsoncMorphError} If this node is not a word literal
+  #[wasm_bindgen(js_name = asWordLitOrThrow)]
+  pub fn as_word_lit_or_throw(&self) -> Result<WordLit, JsValue> {
+    self.as_word_lit().ok_or_else(|| {
+      self.wrong_kind(
+        ErrorCode::WrongValueKind,
+        "Expected a word literal node, but found a different type",
+        "word",
+      )
+    })
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns the parent node, throwing if this is the root.
+  /// @returns The parent node
+  /// @throws {JsoncMorphError} If this node has no parent
+  #[wasm_bindgen(js_name = parentOrThrow)]
+  pub fn parent_or_throw(&self) -> Result<Node, JsValue> {
+    self.parent().ok_or_else(|| {
+      JsoncMorphError::new(
+        ErrorCode::ExpectedParent,
+        "Expected a parent node, but found none",
+      )
+      .at(&self.inner)
+      .into()
+    })
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the root node, throwing if detached.
+  /// @returns The root node
+  /// @throws {JsoncMorphError} If this node is detached from the CST
+  #[wasm_bindgen(js_name = rootNodeOrThrow)]
+  pub fn root_node_or_throw(&self) -> Result<RootNode, JsValue> {
+    self.root_node().ok_or_else(|| {
+      JsoncMorphError::new(
+        ErrorCode::DetachedNode,
+        "Expected a root node, but found none",
+      )
+      .into()
+    })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+
+  /// Returns true if this node is trivia (whitespace or comments).
+  /// @returns true if this node is trivia
+  #[wasm_bindgen(js_name = isTrivia)]
+  pub fn is_trivia(&self) -> bool {
+    self.inner.is_trivia()
+  }
+
+  /// Returns true if this node is a newline character.
+  /// @returns true if this node is a newline
+  #[wasm_bindgen(js_name = isNewline)]
+  pub fn is_newline(&self) -> bool {
+    self.inner.is_newline()
+  }
+
+  /// Returns true if this node is a comma token.
+  /// @returns true if this node is a comma
+  #[wasm_bindgen(js_name = isComma)]
+  pub fn is_comma(&self) -> bool {
+    self.inner.is_comma()
+  }
+
+  /// Returns true if this node is a comment.
+  /// @returns true if this node is a comment
+  #[wasm_bindgen(js_name = isComment)]
+  pub fn is_comment(&self) -> bool {
+    self.inner.is_comment()
+  }
+
+  /// Returns true if this node is a punctuation token (bracket, brace, colon, comma).
+  /// @returns true if this node is a token
+  #[wasm_bindgen(js_name = isToken)]
+  pub fn is_token(&self) -> bool {
+    self.inner.is_token()
+  }
+
+  /// Returns true if this node is whitespace.
+  /// @returns true if this node is whitespace
+  #[wasm_bindgen(js_name = isWhitespace)]
+  pub fn is_whitespace(&self) -> bool {
+    self.inner.is_whitespace()
+  }
+
+  /// Returns the character if this node is a single-character token.
+  /// @returns The token character, or undefined if not a token
+  #[wasm_bindgen(js_name = tokenChar)]
+  pub fn token_char(&self) -> Option<String> {
+    self.inner.token_char().map(|c| c.to_string())
+  }
+
+  /// Returns the element index if this node is an array element.
+  /// @returns The element index, or undefined if not an array element
+  #[wasm_bindgen(js_name = elementIndex)]
+  pub fn element_index(&self) -> Option<usize> {
+    self.inner.element_index()
+  }
+
+  /// Returns all child nodes including whitespace and punctuation.
+  /// @returns Array of all child nodes
+  #[wasm_bindgen(js_name = children)]
+  pub fn children(&self) -> Vec<Node> {
+    self.inner.children().into_iter().map(Node::new).collect()
+  }
+
+  /// Returns child nodes excluding whitespace, comments, and punctuation.
+  /// @returns Array of significant child nodes
+  #[wasm_bindgen(js_name = childrenExcludeTriviaAndTokens)]
+  pub fn children_exclude_trivia_and_tokens(&self) -> Vec<Node> {
+    self
+      .inner
+      .children_exclude_trivia_and_tokens()
+      .into_iter()
+      .map(Node::new)
+      .collect()
+  }
+
+  /// Returns the child node at the specified index.
+  /// @param index - The child index
+  /// @returns The child node, or undefined if index is out of bounds
+  #[wasm_bindgen(js_name = childAtIndex)]
+  pub fn child_at_index(&self, index: usize) -> Option<Node> {
+    self.inner.child_at_index(index).map(Node::new)
+  }
+
+  /// Converts this CST node to a plain JavaScript value.
+  /// This recursively converts objects, arrays, and primitives to their JavaScript equivalents.
+  /// Comments and formatting information are discarded.
+  /// A property converts to its value. Property names, tokens and trivia convert to undefined.
+  /// @param options - Optional conversion options
+  /// @returns The plain JavaScript value (object, array, string, number, boolean, or null), or undefined if the node isn't a value
+  /// @throws {ParseError} If the node's text can't be parsed with the document's parse options
+  /// @throws If an option is invalid
+  #[wasm_bindgen(js_name = toValue)]
+  pub fn to_value(
+    &self,
+    options: Option<ToValueOptionsObject>,
+  ) -> Result<JsValue, JsValue> {
+    let large_integers = match options {
+      Some(opts) => large_integers_from_js(&opts.into())?,
+      None => numbers::LargeIntegers::default(),
+    };
+    let Some(node) = value_node(&self.inner) else {
+      return Ok(JsValue::UNDEFINED);
+    };
+    // parse the text the same way the document was parsed
+    let parse_options = node
+      .root_node()
+      .map(|root| root.parse_options().clone())
+      .unwrap_or_default();
+    let value =
+      text_to_js_value(&node.to_string(), &parse_options, large_integers)?;
+    Ok(value.unwrap_or(JsValue::UNDEFINED))
+  }
+
+  /// Returns the comments before this node, excluding trailing comments of
+  /// the previous element. For a property's name or value, these are the
+  /// property's comments.
+  /// @returns The leading comments
+  #[wasm_bindgen(js_name = leadingComments)]
+  pub fn leading_comments(&self) -> CommentInfos {
+    comment_infos(comments::leading(&self.inner))
+  }
+
+  /// Returns the comments after this node on the same line, including those
+  /// after its comma.
+  /// @returns The trailing comments
+  #[wasm_bindgen(js_name = trailingComments)]
+  pub fn trailing_comments(&self) -> CommentInfos {
+    comment_infos(comments::trailing(&self.inner))
+  }
+
+  /// Returns the comments within this node that aren't leading or trailing
+  /// comments of its children (ex. comments in an empty object).
+  /// @returns The inner comments
+  #[wasm_bindgen(js_name = innerComments)]
+  pub fn inner_comments(&self) -> CommentInfos {
+    comment_infos(comments::inner(&self.inner))
+  }
+
+  /// Replaces this node's leading comments with a single comment, or adds one
+  /// when there are none. An added comment goes on its own line when this node
+  /// starts its line, and before it on the same line otherwise.
+  /// @param text - The text of the comment, without delimiters
+  /// @param options - Optional comment options
+  /// @throws If the text is invalid, or a line comment would comment out the
+  /// rest of the line
+  #[wasm_bindgen(js_name = setLeadingComment)]
+  pub fn set_leading_comment(
+    &self,
+    text: &str,
+    options: Option<CommentOptionsObject>,
+  ) -> Result<(), JsValue> {
+    ensure_attached(&self.inner)?;
+    let kind = comment_kind_from_js(options)?;
+    comments::set_leading(&self.inner, text, kind)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Replaces this node's trailing comments with a single comment, or adds one
+  /// when there are none.
+  /// @param text - The text of the comment, without delimiters
+  /// @param options - Optional comment options
+  /// @throws If the text is invalid, or a line comment would comment out the
+  /// rest of the line
+  #[wasm_bindgen(js_name = setTrailingComment)]
+  pub fn set_trailing_comment(
+    &self,
+    text: &str,
+    options: Option<CommentOptionsObject>,
+  ) -> Result<(), JsValue> {
+    ensure_attached(&self.inner)?;
+    let kind = comment_kind_from_js(options)?;
+    comments::set_trailing(&self.inner, text, kind)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Removes this node's leading and trailing comments.
+  #[wasm_bindgen(js_name = removeComments)]
+  pub fn remove_comments(&self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner)?;
+    comments::remove_all(&self.inner);
+    Ok(())
+  }
+}
+
+/// Represents a JSON object node in the CST.
+/// Provides methods for manipulating object properties.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct JsonObject {
+  inner: cst::CstObject,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl JsonObject {
+  fn new(inner: cst::CstObject) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+
+  fn property_not_found(&self, key: &str) -> JsValue {
+    JsoncMorphError::new(
+      ErrorCode::PropertyNotFound,
+      format!("Expected property '{}', but it was not found", key),
+    )
+    .key(key)
+    .at(&self.inner.clone().into())
+    .into()
+  }
+
+  fn append_value(
+    &self,
+    key: &str,
+    value: values::InsertValue,
+  ) -> Result<ObjectProp, JsValue> {
+    self.insert_value(self.inner.properties().len(), key, value)
+  }
+
+  fn insert_value(
+    &self,
+    index: usize,
+    key: &str,
+    value: values::InsertValue,
+  ) -> Result<ObjectProp, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let prop = value.insert_property(&self.inner, index, key);
+    Ok(ObjectProp::new(prop))
+  }
+}
+
+#[wasm_bindgen]
+impl JsonObject {
+  /// Returns all properties in the object.
+  /// @returns Array of object properties
+  #[wasm_bindgen(js_name = properties)]
+  pub fn properties(&self) -> Vec<ObjectProp> {
+    self
+      .inner
+      .properties()
+      .into_iter()
+      .map(ObjectProp::new)
+      .collect()
+  }
+
+  /// Gets a property by name.
+  /// @param key - The property name to look up
+  /// @returns The property, or undefined if not found
+  #[wasm_bindgen(js_name = get)]
+  pub fn get(&self, key: &str) -> Option<ObjectProp> {
+    self.inner.get(key).map(ObjectProp::new)
+  }
+
+  /// Gets a property by name, throwing if not found.
+  /// @param key - The property name to look up
+  /// @returns The property
+  /// @throws {JsoncMorphError} If the property is not found
+  #[wasm_bindgen(js_name = getOrThrow)]
+  pub fn get_or_throw(&self, key: &str) -> Result<ObjectProp, JsValue> {
+    self.get(key).ok_or_else(|| self.property_not_found(key))
+  }
+
+  /// Gets a property value if it's an object.
+  /// @param name - The property name to look up
+  /// @returns The object value, or undefined if property doesn't exist or is not an object
+  #[wasm_bindgen(js_name = getIfObject)]
+  pub fn get_if_object(&self, name: &str) -> Option<JsonObject> {
+    self.inner.object_value(name).map(JsonObject::new)
+  }
+
+  /// Gets a property value as an object, throwing if not found or wrong type.
+  /// @param name - The property name to look up
+  /// @returns The object value
+  /// @throws {JsoncMorphError} If the property doesn't exist or is not an object
+  #[wasm_bindgen(js_name = getIfObjectOrThrow)]
+  pub fn get_if_object_or_throw(
+    &self,
+    name: &str,
+  ) -> Result<JsonObject, JsValue> {
+    let prop = self.get_or_throw(name)?;
+    prop.value_if_object().ok_or_else(|| {
+      prop.wrong_value_kind(
+        ErrorCode::ExpectedObject,
+        &format!(
+          "Expected property '{}' to have an object value, but it has a different type",
+          name
+        ),
+        "object",
+      )
+    })
+  }
+
+  /// Gets a property value as an object, creating an empty object if the property doesn't exist.
+  /// Returns undefined if the property exists but has a non-object value.
+  /// @param name - The property name to get
+  /// @returns The object value, or undefined if property has a non-object value
+  #[wasm_bindgen(js_name = getIfObjectOrCreate)]
+  pub fn get_if_object_or_create(
+    &self,
+    name: &str,
+  ) -> Result<Option<JsonObject>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    Ok(self.inner.object_value_or_create(name).map(JsonObject::new))
+  }
+
+  /// Gets a property value as an object, creating or replacing the value with an empty object if needed.
+  /// Unlike getIfObjectOrCreate, this always returns an object by replacing non-object values.
+  /// @param name - The property name to get
+  /// @returns The object value (always succeeds)
+  #[wasm_bindgen(js_name = getIfObjectOrForce)]
+  pub fn get_if_object_or_force(
+    &self,
+    name: &str,
+  ) -> Result<JsonObject, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    Ok(JsonObject::new(self.inner.object_value_or_set(name)))
+  }
+
+  /// Applies a JSON Merge Patch (RFC 7396) to this object.
+  /// Properties set to null are removed, objects are merged recursively, and other
+  /// values are replaced. Untouched properties keep their comments and formatting.
+  /// @param patch - The merge patch object
+  /// @throws If the patch is not an object
+  #[wasm_bindgen(js_name = mergePatch)]
+  pub fn merge_patch(&self, patch: JsValue) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    match js_value_to_serde_value(&patch)? {
+      serde_json::Value::Object(patch) => {
+        merge::merge_into_object(&self.inner, &patch);
+        Ok(())
+      }
+      _ => Err(throw_error("Expected the merge patch to be an object")),
+    }
+  }
+
+  /// Sorts the properties of this object by name, or by a comparison function.
+  /// Properties are moved along with their comments, and as few properties as
+  /// possible are moved.
+  /// @param compareFn - Optional function that compares two property names
+  /// @param options - Optional sort options
+  /// @throws If the comparison function throws, in which case the object is
+  /// left unchanged
+  #[wasm_bindgen(js_name = sortProperties)]
+  pub fn sort_properties(
+    &self,
+    compare_fn: Option<PropertyCompareFn>,
+    options: Option<SortOptionsObject>,
+  ) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let deep = options
+      .and_then(|o| js_sys::Reflect::get(&o, &"sortKeysDeep".into()).ok())
+      .and_then(|v| v.as_bool())
+      .unwrap_or(false);
+    match compare_fn {
+      Some(compare_fn) => {
+        let compare_fn: &js_sys::Function = compare_fn.unchecked_ref();
+        sort::sort_properties(&self.inner, deep, &mut |a, b| {
+          let result =
+            compare_fn.call2(&JsValue::NULL, &a.into(), &b.into())?;
+          Ok(
+            result
+              .as_f64()
+              .unwrap_or(0.0)
+              .partial_cmp(&0.0)
+              .unwrap_or(std::cmp::Ordering::Equal),
+          )
+        })
+      }
+      None => sort::sort_properties(&self.inner, deep, &mut |a, b| {
+        Ok::<_, JsValue>(sort::compare_names(a, b))
+      }),
+    }
+  }
+
+  /// Gets a property value if it's an array.
+  /// @param name - The property name to look up
+  /// @returns The array value, or undefined if property doesn't exist or is not an array
+  #[wasm_bindgen(js_name = getIfArray)]
+  pub fn get_if_array(&self, name: &str) -> Option<JsonArray> {
+    self.inner.array_value(name).map(JsonArray::new)
+  }
+
+  /// Gets a property value as an array, throwing if not found or wrong type.
+  /// @param name - The property name to look up
+  /// @returns The array value
+  /// @throws {JsoncMorphError} If the property doesn't exist or is not an array
+  #[wasm_bindgen(js_name = getIfArrayOrThrow)]
+  pub fn get_if_array_or_throw(
+    &self,
+    name: &str,
+  ) -> Result<JsonArray, JsValue> {
+    let prop = self.get_or_throw(name)?;
+    prop.value_if_array().ok_or_else(|| {
+      prop.wrong_value_kind(
+        ErrorCode::ExpectedArray,
+        &format!(
+          "Expected property '{}' to have an array value, but it has a different type",
+          name
+        ),
+        "array",
+      )
+    })
+  }
+
+  /// Gets a property value as an array, creating an empty array if the property doesn't exist.
+  /// Returns undefined if the property exists but has a non-array value.
+  /// @param name - The property name to get
+  /// @returns The array value, or undefined if property has a non-array value
+  #[wasm_bindgen(js_name = getIfArrayOrCreate)]
+  pub fn get_if_array_or_create(
+    &self,
+    name: &str,
+  ) -> Result<Option<JsonArray>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    Ok(self.inner.array_value_or_create(name).map(JsonArray::new))
+  }
+
+  /// Gets a property value as an array, creating or replacing the value with an empty array if needed.
+  /// Unlike getIfArrayOrCreate, this always returns an array by replacing non-array values.
+  /// @param name - The property name to get
+  /// @returns The array value (always succeeds)
+  #[wasm_bindgen(js_name = getIfArrayOrForce)]
+  pub fn get_if_array_or_force(
+    &self,
+    name: &str,
+  ) -> Result<JsonArray, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    Ok(JsonArray::new(self.inner.array_value_or_set(name)))
+  }
+
+  /// Removes this object from its parent.
+  /// After calling this method, the object is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Returns all child nodes including whitespace and punctuation.
+  /// @returns Array of all child nodes
+  #[wasm_bindgen(js_name = children)]
+  pub fn children(&self) -> Vec<Node> {
+    self.inner.children().into_iter().map(Node::new).collect()
+  }
+
+  /// Appends a new property to the object. A node's value is copied from this
+  /// or another document as is, along with the comments around a property or
+  /// array element.
+  /// @param key - The name of the property to add
+  /// @param value - The value to set for the property, or a node to copy
+  /// @returns The newly created property
+  #[wasm_bindgen(js_name = append)]
+  pub fn append(
+    &self,
+    key: &str,
+    value: JsValue,
+  ) -> Result<ObjectProp, JsValue> {
+    self.append_value(key, insert_value_from_js(&value)?)
+  }
+
+  /// Inserts a new property at the specified index. A node's value is copied
+  /// from this or another document as is, along with the comments around a
+  /// property or array element.
+  /// @param index - The position to insert the property at
+  /// @param key - The name of the property to add
+  /// @param value - The value to set for the property, or a node to copy
+  /// @returns The newly created property
+  #[wasm_bindgen(js_name = insert)]
+  pub fn insert(
+    &self,
+    index: usize,
+    key: &str,
+    value: JsValue,
+  ) -> Result<ObjectProp, JsValue> {
+    self.insert_value(index, key, insert_value_from_js(&value)?)
+  }
+
+  /// Appends a new property whose value is parsed from JSONC text, keeping the
+  /// spelling of its strings and numbers (ex. `'text'`, `1.0` or `0xFF`).
+  /// The text is inserted as is, including its formatting and comments.
+  /// @param key - The name of the property to add
+  /// @param text - The JSONC text of the value
+  /// @param options - Options for parsing the text, which default to the
+  /// options the document was parsed with
+  /// @returns The newly created property
+  /// @throws {ParseError} If the text cannot be parsed
+  #[wasm_bindgen(js_name = appendRaw)]
+  pub fn append_raw(
+    &self,
+    key: &str,
+    text: &str,
+    options: Option<JsoncParseOptionsObject>,
+  ) -> Result<ObjectProp, JsValue> {
+    let value = parse_insert_value(text, options, &self.inner.clone().into())?;
+    self.append_value(key, value)
+  }
+
+  /// Inserts a new property whose value is parsed from JSONC text, keeping the
+  /// spelling of its strings and numbers (ex. `'text'`, `1.0` or `0xFF`).
+  /// The text is inserted as is, including its formatting and comments.
+  /// @param index - The position to insert the property at
+  /// @param key - The name of the property to add
+  /// @param text - The JSONC text of the value
+  /// @param options - Options for parsing the text, which default to the
+  /// options the document was parsed with
+  /// @returns The newly created property
+  /// @throws {ParseError} If the text cannot be parsed
+  #[wasm_bindgen(js_name = insertRaw)]
+  pub fn insert_raw(
+    &self,
+    index: usize,
+    key: &str,
+    text: &str,
+    options: Option<JsoncParseOptionsObject>,
+  ) -> Result<ObjectProp, JsValue> {
+    let value = parse_insert_value(text, options, &self.inner.clone().into())?;
+    self.insert_value(index, key, value)
+  }
+
+  /// Configures whether trailing commas should be used in this object.
+  /// When enabled, trailing commas are added for multiline formatting.
+  /// @param enabled - Whether to enable trailing commas
+  #[wasm_bindgen(js_name = setTrailingCommas)]
+  pub fn set_trailing_commas(&self, enabled: bool) -> Result<(), JsValue> {
+    use jsonc_parser::cst::TrailingCommaMode;
+    ensure_attached(&self.inner.clone().into())?;
+    let mode = if enabled {
+      TrailingCommaMode::IfMultiline
+    } else {
+      TrailingCommaMode::Never
+    };
+    self.inner.set_trailing_commas(mode);
+    Ok(())
+  }
+
+  /// Ensures the object is formatted with each property on its own line.
+  #[wasm_bindgen(js_name = ensureMultiline)]
+  pub fn ensure_multiline(&self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.ensure_multiline();
+    Ok(())
+  }
+
+  /// Puts the object on a single line, along with the objects and arrays
+  /// within it, by removing newlines and indentation. Trailing commas are
+  /// removed. Comments and other whitespace are kept.
+  /// @throws If the object has a line comment, which would comment out the rest of the line
+  #[wasm_bindgen(js_name = ensureSingleLine)]
+  pub fn ensure_single_line(&self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    layout::ensure_single_line(&self.inner.clone().into())
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Checks if the object has a newline between its braces, excluding
+  /// newlines within nested objects and arrays.
+  /// @returns true if the object spans multiple lines
+  #[wasm_bindgen(js_name = isMultiline)]
+  pub fn is_multiline(&self) -> bool {
+    layout::is_multiline(&self.inner.clone().into())
+  }
+
+  /// Replaces this object with a new value.
+  /// @param replacement - The new value to replace this object with
+  /// @returns The new node that replaced this one, or undefined if this was the root value
+  #[wasm_bindgen(js_name = replaceWith)]
+  pub fn replace_with(
+    &self,
+    replacement: JsValue,
+  ) -> Result<Option<Node>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&replacement)?;
+    Ok(self.inner.clone().replace_with(cst_input).map(Node::new))
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+
+  /// Returns child nodes excluding whitespace, comments, and punctuation.
+  /// @returns Array of significant child nodes
+  #[wasm_bindgen(js_name = childrenExcludeTriviaAndTokens)]
+  pub fn children_exclude_trivia_and_tokens(&self) -> Vec<Node> {
+    self
+      .inner
+      .children_exclude_trivia_and_tokens()
+      .into_iter()
+      .map(Node::new)
+      .collect()
+  }
+
+  /// Returns the child node at the specified index.
+  /// @param index - The child index
+  /// @returns The child node, or undefined if index is out of bounds
+  #[wasm_bindgen(js_name = childAtIndex)]
+  pub fn child_at_index(&self, index: usize) -> Option<Node> {
+    self.inner.child_at_index(index).map(Node::new)
+  }
+
+  /// Returns the comments before this node, excluding trailing comments of
+  /// the previous element. For a property's name or value, these are the
+  /// property's comments.
+  /// @returns The leading comments
+  #[wasm_bindgen(js_name = leadingComments)]
+  pub fn leading_comments(&self) -> CommentInfos {
+    comment_infos(comments::leading(&self.inner.clone().into()))
+  }
+
+  /// Returns the comments after this node on the same line, including those
+  /// after its comma.
+  /// @returns The trailing comments
+  #[wasm_bindgen(js_name = trailingComments)]
+  pub fn trailing_comments(&self) -> CommentInfos {
+    comment_infos(comments::trailing(&self.inner.clone().into()))
+  }
+
+  /// Returns the comments within this node that aren't leading or trailing
+  /// comments of its children (ex. comments in an empty object).
+  /// @returns The inner comments
+  #[wasm_bindgen(js_name = innerComments)]
+  pub fn inner_comments(&self) -> CommentInfos {
+    comment_infos(comments::inner(&self.inner.clone().into()))
+  }
+}
+
+/// Represents the name part of an object property in the CST.
+/// Can be either a quoted string or an unquoted word literal (when allowLooseObjectPropertyNames is enabled).
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ObjectPropName {
+  inner: cst::ObjectPropName,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl ObjectPropName {
+  fn new(inner: cst::ObjectPropName) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl ObjectPropName {
+  /// Returns the decoded property name (unquoted and unescaped).
+  /// @returns The decoded property name
+  #[wasm_bindgen(js_name = decodedValue)]
+  pub fn decoded_value(&self) -> Result<String, JsValue> {
+    self.inner.decoded_value().map_err(|e| {
+      throw_error(&format!("Failed to decode property name: {}", e))
+    })
+  }
+
+  /// Sets the property name, keeping its quote style. A name without quotes
+  /// is changed to a double quoted string when the new name isn't a valid
+  /// identifier, in which case this node is replaced.
+  /// @param name - The new property name
+  /// @returns The property name node, which is a new node if it was replaced
+  #[wasm_bindgen(js_name = setValue)]
+  pub fn set_value(&self, name: &str) -> Result<ObjectPropName, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    Ok(ObjectPropName::new(prop_name::set_name(&self.inner, name)))
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+}
+
+/// Represents an object property (key-value pair) in the CST.
+/// Provides methods for accessing and manipulating both the property name and its value.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ObjectProp {
+  inner: cst::CstObjectProp,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl ObjectProp {
+  fn new(inner: cst::CstObjectProp) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+
+  /// Creates the error for when the property value isn't the expected kind.
+  fn wrong_value_kind(
+    &self,
+    code: ErrorCode,
+    message: &str,
+    expected: &'static str,
+  ) -> JsValue {
+    let value = self.inner.value();
+    let node = value.clone().unwrap_or_else(|| self.inner.clone().into());
+    let error = JsoncMorphError::new(code, message)
+      .kinds(expected, value.as_ref())
+      .at(&node);
+    match self.inner.name().and_then(|name| name.decoded_value().ok()) {
+      Some(name) => error.key(&name).into(),
+      None => error.into(),
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl ObjectProp {
+  /// Returns the property name.
+  /// @returns The property name, or undefined if malformed
+  #[wasm_bindgen(js_name = name)]
+  pub fn name(&self) -> Option<ObjectPropName> {
+    self.inner.name().map(ObjectPropName::new)
+  }
+
+  /// Returns the property name, throwing if malformed.
+  /// @returns The property name
+  /// @throws {JsoncMorphError} If the property name is malformed
+  #[wasm_bindgen(js_name = nameOrThrow)]
+  pub fn name_or_throw(&self) -> Result<ObjectPropName, JsValue> {
+    self.name().ok_or_else(|| {
+      JsoncMorphError::new(
+        ErrorCode::ExpectedName,
+        "Expected a property name, but found none",
+      )
+      .at(&self.inner.clone().into())
+      .into()
+    })
+  }
+
+  /// Returns the property value.
+  /// @returns The property value, or undefined if malformed
+  #[wasm_bindgen(js_name = value)]
+  pub fn value(&self) -> Option<Node> {
+    self.inner.value().map(Node::new)
+  }
+
+  /// Returns the property value, throwing if malformed.
+  /// @returns The property value
+  /// @throws {JsoncMorphError} If the property value is malformed
+  #[wasm_bindgen(js_name = valueOrThrow)]
+  pub fn value_or_throw(&self) -> Result<Node, JsValue> {
+    self.value().ok_or_else(|| {
+      JsoncMorphError::new(
+        ErrorCode::ExpectedValue,
+        "Expected a property value, but found none",
+      )
+      .at(&self.inner.clone().into())
+      .into()
+    })
+  }
+
+  /// Returns the property value if it's an object.
+  /// @returns The object value, or undefined if not an object
+  #[wasm_bindgen(js_name = valueIfObject)]
+  pub fn value_if_object(&self) -> Option<JsonObject> {
+    self.inner.object_value().map(JsonObject::new)
+  }
+
+  /// Returns the property value as an object, throwing if not an object.
+  /// @returns The object value
+  /// @throws {JsoncMorphError} If the property value is not an object
+  #[wasm_bindgen(js_name = valueIfObjectOrThrow)]
+  pub fn value_if_object_or_throw(&self) -> Result<JsonObject, JsValue> {
+    self.value_if_object().ok_or_else(|| {
+      self.wrong_value_kind(
+        ErrorCode::ExpectedObject,
+        "Expected property to have an object value, but it has a different type",
+        "object",
+      )
+    })
+  }
+
+  /// Gets the property value as an object, replacing the value with an empty object if needed.
+  /// Always returns an object by replacing non-object values.
+  /// @returns The object value (always succeeds)
+  #[wasm_bindgen(js_name = valueIfObjectOrForce)]
+  pub fn value_if_object_or_force(&self) -> Result<JsonObject, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    Ok(JsonObject::new(self.inner.object_value_or_set()))
+  }
+
+  /// Returns the property value if it's an array.
+  /// @returns The array value, or undefined if not an array
+  #[wasm_bindgen(js_name = valueIfArray)]
+  pub fn value_if_array(&self) -> Option<JsonArray> {
+    self.inner.array_value().map(JsonArray::new)
+  }
+
+  /// Returns the property value as an array, throwing if not an array.
+  /// @returns The array value
+  /// @throws {JsoncMorphError} If the property value is not an array
+  #[wasm_bindgen(js_name = valueIfArrayOrThrow)]
+  pub fn value_if_array_or_throw(&self) -> Result<JsonArray, JsValue> {
+    self.value_if_array().ok_or_else(|| {
+      self.wrong_value_kind(
+        ErrorCode::ExpectedArray,
+        "Expected property to have an array value, but it has a different type",
+        "array",
+      )
+    })
+  }
+
+  /// Gets the property value as an array, replacing the value with an empty array if needed.
+  /// Always returns an array by replacing non-array values.
+  /// @returns The array value (always succeeds)
+  #[wasm_bindgen(js_name = valueIfArrayOrForce)]
+  pub fn value_if_array_or_force(&self) -> Result<JsonArray, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    Ok(JsonArray::new(self.inner.array_value_or_set()))
+  }
+
+  /// Removes this property from its parent object.
+  /// After calling this method, the property is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Returns the index of this property within its parent object.
+  /// @returns The property index
+  #[wasm_bindgen(js_name = propertyIndex)]
+  pub fn property_index(&self) -> usize {
+    self.inner.property_index()
+  }
+
+  /// Sets the value of this property.
+  /// @param value - The new value to set
+  #[wasm_bindgen(js_name = setValue)]
+  pub fn set_value(&self, value: JsValue) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&value)?;
+    self.inner.set_value(cst_input);
+    Ok(())
+  }
+
+  /// Sets the value of this property to a value parsed from JSONC text,
+  /// keeping the spelling of its strings and numbers (ex. `'text'`, `1.0` or
+  /// `0xFF`).
+  /// The text is inserted as is, including its formatting and comments.
+  /// @param text - The JSONC text of the value
+  /// @param options - Options for parsing the text, which default to the
+  /// options the document was parsed with
+  /// @throws {ParseError} If the text cannot be parsed
+  #[wasm_bindgen(js_name = setValueRaw)]
+  pub fn set_value_raw(
+    &self,
+    text: &str,
+    options: Option<JsoncParseOptionsObject>,
+  ) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let value = parse_insert_value(text, options, &self.inner.clone().into())?;
+    value.set_property_value(&self.inner);
+    Ok(())
+  }
+
+  /// Renames this property, keeping the quote style of its name.
+  /// The value and any comments are left unchanged.
+  /// @param name - The new property name
+  /// @throws If the property name is malformed
+  #[wasm_bindgen(js_name = rename)]
+  pub fn rename(&self, name: &str) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    prop_name::set_name(&self.name_or_throw()?.inner, name);
+    Ok(())
+  }
+
+  /// Moves this property to the index within its object, along with its
+  /// comments and the formatting within it.
+  /// @param index - The new property index
+  /// @returns This property
+  /// @throws If the index is out of bounds
+  #[wasm_bindgen(js_name = moveTo)]
+  pub fn move_to(&self, index: usize) -> Result<ObjectProp, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    reorder::move_property(&self.inner, index)
+      .map(ObjectProp::new)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Moves this property to the index of another object, which may be in
+  /// another document, along with its comments and the formatting within it.
+  /// @param object - The object to move the property to
+  /// @param index - The property index within the object
+  /// @returns This property
+  /// @throws If the index is out of bounds or the object is within this
+  /// property's value
+  #[wasm_bindgen(js_name = moveToObject)]
+  pub fn move_to_object(
+    &self,
+    object: &JsonObject,
+    index: usize,
+  ) -> Result<ObjectProp, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    ensure_attached(&object.inner.clone().into())?;
+    reorder::move_property_to_object(&self.inner, &object.inner, index)
+      .map(ObjectProp::new)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Replaces this property with a new property.
+  /// This allows changing both the property name and its value.
+  /// @param key - The new property name
+  /// @param replacement - The new value for the property
+  /// @returns The new node that replaced this property, or undefined if this was the root value
+  #[wasm_bindgen(js_name = replaceWith)]
+  pub fn replace_with(
+    &self,
+    key: &str,
+    replacement: JsValue,
+  ) -> Result<Option<Node>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&replacement)?;
+    Ok(
+      self
+        .inner
+        .clone()
+        .replace_with(key, cst_input)
+        .map(Node::new),
+    )
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the previous property in the same object.
+  /// @returns The previous property, or undefined if this is the first property
+  #[wasm_bindgen(js_name = previousProperty)]
+  pub fn previous_property(&self) -> Option<ObjectProp> {
+    self.inner.previous_property().map(ObjectProp::new)
+  }
+
+  /// Returns the next property in the same object.
+  /// @returns The next property, or undefined if this is the last property
+  #[wasm_bindgen(js_name = nextProperty)]
+  pub fn next_property(&self) -> Option<ObjectProp> {
+    self.inner.next_property().map(ObjectProp::new)
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+
+  /// Returns all child nodes including whitespace and punctuation.
+  /// @returns Array of all child nodes
+  #[wasm_bindgen(js_name = children)]
+  pub fn children(&self) -> Vec<Node> {
+    self.inner.children().into_iter().map(Node::new).collect()
+  }
+
+  /// Returns child nodes excluding whitespace, comments, and punctuation.
+  /// @returns Array of significant child nodes
+  #[wasm_bindgen(js_name = childrenExcludeTriviaAndTokens)]
+  pub fn children_exclude_trivia_and_tokens(&self) -> Vec<Node> {
+    self
+      .inner
+      .children_exclude_trivia_and_tokens()
+      .into_iter()
+      .map(Node::new)
+      .collect()
+  }
+
+  /// Returns the child node at the specified index.
+  /// @param index - The child index
+  /// @returns The child node, or undefined if index is out of bounds
+  #[wasm_bindgen(js_name = childAtIndex)]
+  pub fn child_at_index(&self, index: usize) -> Option<Node> {
+    self.inner.child_at_index(index).map(Node::new)
+  }
+
+  /// Returns the comments before this node, excluding trailing comments of
+  /// the previous element. For a property's name or value, these are the
+  /// property's comments.
+  /// @returns The leading comments
+  #[wasm_bindgen(js_name = leadingComments)]
+  pub fn leading_comments(&self) -> CommentInfos {
+    comment_infos(comments::leading(&self.inner.clone().into()))
+  }
+
+  /// Returns the comments after this node on the same line, including those
+  /// after its comma.
+  /// @returns The trailing comments
+  #[wasm_bindgen(js_name = trailingComments)]
+  pub fn trailing_comments(&self) -> CommentInfos {
+    comment_infos(comments::trailing(&self.inner.clone().into()))
+  }
+
+  /// Returns the comments within this node that aren't leading or trailing
+  /// comments of its children (ex. comments in an empty object).
+  /// @returns The inner comments
+  #[wasm_bindgen(js_name = innerComments)]
+  pub fn inner_comments(&self) -> CommentInfos {
+    comment_infos(comments::inner(&self.inner.clone().into()))
+  }
+
+  /// Replaces this node's leading comments with a single comment, or adds one
+  /// when there are none. An added comment goes on its own line when this node
+  /// starts its line, and before it on the same line otherwise.
+  /// @param text - The text of the comment, without delimiters
+  /// @param options - Optional comment options
+  /// @throws If the text is invalid, or a line comment would comment out the
+  /// rest of the line
+  #[wasm_bindgen(js_name = setLeadingComment)]
+  pub fn set_leading_comment(
+    &self,
+    text: &str,
+    options: Option<CommentOptionsObject>,
+  ) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let kind = comment_kind_from_js(options)?;
+    comments::set_leading(&self.inner.clone().into(), text, kind)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Replaces this node's trailing comments with a single comment, or adds one
+  /// when there are none.
+  /// @param text - The text of the comment, without delimiters
+  /// @param options - Optional comment options
+  /// @throws If the text is invalid, or a line comment would comment out the
+  /// rest of the line
+  #[wasm_bindgen(js_name = setTrailingComment)]
+  pub fn set_trailing_comment(
+    &self,
+    text: &str,
+    options: Option<CommentOptionsObject>,
+  ) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let kind = comment_kind_from_js(options)?;
+    comments::set_trailing(&self.inner.clone().into(), text, kind)
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Removes this node's leading and trailing comments.
+  #[wasm_bindgen(js_name = removeComments)]
+  pub fn remove_comments(&self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    comments::remove_all(&self.inner.clone().into());
+    Ok(())
+  }
+}
+
+/// Represents a JSON array node in the CST.
+/// Provides methods for manipulating array elements.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct JsonArray {
+  inner: cst::CstArray,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl JsonArray {
+  fn new(inner: cst::CstArray) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+
+  fn append_value(&self, value: values::InsertValue) -> Result<Node, JsValue> {
+    self.insert_value(self.inner.elements().len(), value)
+  }
+
+  fn insert_value(
+    &self,
+    index: usize,
+    value: values::InsertValue,
+  ) -> Result<Node, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    Ok(Node::new(value.insert_element(&self.inner, index)))
+  }
+}
+
+#[wasm_bindgen]
+impl JsonArray {
+  /// Returns all element nodes in the array.
+  /// @returns Array of element nodes
+  #[wasm_bindgen(js_name = elements)]
+  pub fn elements(&self) -> Vec<Node> {
+    self.inner.elements().into_iter().map(Node::new).collect()
+  }
+
+  /// Removes this array from its parent.
+  /// After calling this method, the array is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Ensures the array is formatted with each element on its own line.
+  #[wasm_bindgen(js_name = ensureMultiline)]
+  pub fn ensure_multiline(&self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.ensure_multiline();
+    Ok(())
+  }
+
+  /// Puts the array on a single line, along with the objects and arrays
+  /// within it, by removing newlines and indentation. Trailing commas are
+  /// removed. Comments and other whitespace are kept.
+  /// @throws If the array has a line comment, which would comment out the rest of the line
+  #[wasm_bindgen(js_name = ensureSingleLine)]
+  pub fn ensure_single_line(&self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    layout::ensure_single_line(&self.inner.clone().into())
+      .map_err(|e| throw_error(&e.to_string()))
+  }
+
+  /// Checks if the array has a newline between its brackets, excluding
+  /// newlines within nested objects and arrays.
+  /// @returns true if the array spans multiple lines
+  #[wasm_bindgen(js_name = isMultiline)]
+  pub fn is_multiline(&self) -> bool {
+    layout::is_multiline(&self.inner.clone().into())
+  }
+
+  /// Returns all child nodes including whitespace and punctuation.
+  /// @returns Array of all child nodes
+  #[wasm_bindgen(js_name = children)]
+  pub fn children(&self) -> Vec<Node> {
+    self.inner.children().into_iter().map(Node::new).collect()
+  }
+
+  /// Appends a new element to the end of the array. A node's value is copied
+  /// from this or another document as is, along with the comments around a
+  /// property or array element.
+  /// @param value - The value to append, or a node to copy
+  /// @returns The newly created element node
+  #[wasm_bindgen(js_name = append)]
+  pub fn append(&self, value: JsValue) -> Result<Node, JsValue> {
+    self.append_value(insert_value_from_js(&value)?)
+  }
+
+  /// Inserts a new element at the specified index. A node's value is copied
+  /// from this or another document as is, along with the comments around a
+  /// property or array element.
+  /// @param index - The position to insert at
+  /// @param value - The value to insert, or a node to copy
+  /// @returns The newly created element node
+  #[wasm_bindgen(js_name = insert)]
+  pub fn insert(&self, index: usize, value: JsValue) -> Result<Node, JsValue> {
+    self.insert_value(index, insert_value_from_js(&value)?)
+  }
+
+  /// Appends a new element parsed from JSONC text, keeping the spelling of
+  /// its strings and numbers (ex. `'text'`, `1.0` or `0xFF`).
+  /// The text is inserted as is, including its formatting and comments.
+  /// @param text - The JSONC text of the value
+  /// @param options - Options for parsing the text, which default to the
+  /// options the document was parsed with
+  /// @returns The newly created element node
+  /// @throws {ParseError} If the text cannot be parsed
+  #[wasm_bindgen(js_name = appendRaw)]
+  pub fn append_raw(
+    &self,
+    text: &str,
+    options: Option<JsoncParseOptionsObject>,
+  ) -> Result<Node, JsValue> {
+    let value = parse_insert_value(text, options, &self.inner.clone().into())?;
+    self.append_value(value)
+  }
+
+  /// Inserts a new element parsed from JSONC text, keeping the spelling of
+  /// its strings and numbers (ex. `'text'`, `1.0` or `0xFF`).
+  /// The text is inserted as is, including its formatting and comments.
+  /// @param index - The position to insert at
+  /// @param text - The JSONC text of the value
+  /// @param options - Options for parsing the text, which default to the
+  /// options the document was parsed with
+  /// @returns The newly created element node
+  /// @throws {ParseError} If the text cannot be parsed
+  #[wasm_bindgen(js_name = insertRaw)]
+  pub fn insert_raw(
+    &self,
+    index: usize,
+    text: &str,
+    options: Option<JsoncParseOptionsObject>,
+  ) -> Result<Node, JsValue> {
+    let value = parse_insert_value(text, options, &self.inner.clone().into())?;
+    self.insert_value(index, value)
+  }
+
+  /// Configures whether trailing commas should be used in this array.
+  /// When enabled, trailing commas are added for multiline formatting.
+  /// @param enabled - Whether to enable trailing commas
+  #[wasm_bindgen(js_name = setTrailingCommas)]
+  pub fn set_trailing_commas(&self, enabled: bool) -> Result<(), JsValue> {
+    use jsonc_parser::cst::TrailingCommaMode;
+    ensure_attached(&self.inner.clone().into())?;
+    let mode = if enabled {
+      TrailingCommaMode::IfMultiline
+    } else {
+      TrailingCommaMode::Never
+    };
+    self.inner.set_trailing_commas(mode);
+    Ok(())
+  }
+
+  /// Replaces this array with a new value.
+  /// @param value - The new value to replace this array with
+  /// @returns The new node that replaced this one, or undefined if this was the root value
+  #[wasm_bindgen(js_name = replaceWith)]
+  pub fn replace_with(&self, value: JsValue) -> Result<Option<Node>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&value)?;
+    Ok(self.inner.clone().replace_with(cst_input).map(Node::new))
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+
+  /// Returns child nodes excluding whitespace, comments, and punctuation.
+  /// @returns Array of significant child nodes
+  #[wasm_bindgen(js_name = childrenExcludeTriviaAndTokens)]
+  pub fn children_exclude_trivia_and_tokens(&self) -> Vec<Node> {
+    self
+      .inner
+      .children_exclude_trivia_and_tokens()
+      .into_iter()
+      .map(Node::new)
+      .collect()
+  }
+
+  /// Returns the child node at the specified index.
+  /// @param index - The child index
+  /// @returns The child node, or undefined if index is out of bounds
+  #[wasm_bindgen(js_name = childAtIndex)]
+  pub fn child_at_index(&self, index: usize) -> Option<Node> {
+    self.inner.child_at_index(index).map(Node::new)
+  }
+
+  /// Returns the comments before this node, excluding trailing comments of
+  /// the previous element. For a property's name or value, these are the
+  /// property's comments.
+  /// @returns The leading comments
+  #[wasm_bindgen(js_name = leadingComments)]
+  pub fn leading_comments(&self) -> CommentInfos {
+    comment_infos(comments::leading(&self.inner.clone().into()))
+  }
+
+  /// Returns the comments after this node on the same line, including those
+  /// after its comma.
+  /// @returns The trailing comments
+  #[wasm_bindgen(js_name = trailingComments)]
+  pub fn trailing_comments(&self) -> CommentInfos {
+    comment_infos(comments::trailing(&self.inner.clone().into()))
+  }
+
+  /// Returns the comments within this node that aren't leading or trailing
+  /// comments of its children (ex. comments in an empty object).
+  /// @returns The inner comments
+  #[wasm_bindgen(js_name = innerComments)]
+  pub fn inner_comments(&self) -> CommentInfos {
+    comment_infos(comments::inner(&self.inner.clone().into()))
+  }
+}
+
+/// Represents a string literal node in the CST.
+/// Provides methods for manipulating string values and their formatting.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct StringLit {
+  inner: cst::CstStringLit,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl StringLit {
+  fn new(inner: cst::CstStringLit) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl StringLit {
+  /// Returns the decoded string value (without quotes and with escape sequences processed).
+  /// @returns The decoded string value
+  #[wasm_bindgen(js_name = decodedValue)]
+  pub fn decoded_value(&self) -> Result<String, JsValue> {
+    self
+      .inner
+      .decoded_value()
+      .map_err(|e| throw_error(&format!("Failed to decode string: {}", e)))
+  }
+
+  /// Returns the raw string value including quotes and escape sequences.
+  /// @returns The raw string representation
+  #[wasm_bindgen(js_name = rawValue)]
+  pub fn raw_value(&self) -> String {
+    self.inner.raw_value().to_string()
+  }
+
+  /// Sets the raw string value (should include quotes).
+  /// @param value - The new raw string value
+  #[wasm_bindgen(js_name = setRawValue)]
+  pub fn set_raw_value(&self, value: String) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.set_raw_value(value);
+    Ok(())
+  }
+
+  /// Replaces this string literal with a new value.
+  /// @param replacement - The new value to replace this string with
+  /// @returns The new node that replaced this one, or undefined if this was the root value
+  #[wasm_bindgen(js_name = replaceWith)]
+  pub fn replace_with(
+    &self,
+    replacement: JsValue,
+  ) -> Result<Option<Node>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&replacement)?;
+    Ok(self.inner.clone().replace_with(cst_input).map(Node::new))
+  }
+
+  /// Removes this string literal from its parent.
+  /// After calling this method, the node is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+}
+
+/// Represents a number literal node in the CST.
+/// Provides methods for manipulating number values.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct NumberLit {
+  inner: cst::CstNumberLit,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl NumberLit {
+  fn new(inner: cst::CstNumberLit) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl NumberLit {
+  /// Returns the raw string representation of the number.
+  /// Returns a string to preserve the exact formatting (e.g., "1.0" vs "1", "1e10" vs "10000000000").
+  /// @returns The number as a string
+  #[wasm_bindgen(js_name = value)]
+  pub fn value(&self) -> String {
+    self.inner.to_string()
+  }
+
+  /// Sets the raw number value.
+  /// The value must be a valid number literal (e.g., "42", "3.14", "1e10", "0xFF").
+  /// @param value - The raw number string to set
+  /// @throws If the value isn't a number literal
+  #[wasm_bindgen(js_name = setRawValue)]
+  pub fn set_raw_value(&self, value: String) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    numbers::validate_literal(&value)
+      .map_err(|e| throw_error(&e.to_string()))?;
+    self.inner.set_raw_value(value);
+    Ok(())
+  }
+
+  /// Returns the value of the number, which may lose precision for large
+  /// integers (see `asBigInt`).
+  /// @returns The number
+  /// @throws If the number literal is invalid
+  #[wasm_bindgen(js_name = asNumber)]
+  pub fn as_number(&self) -> Result<f64, JsValue> {
+    let raw = self.inner.to_string();
+    numbers::parse(&raw)
+      .map(|number| number.to_f64())
+      .ok_or_else(|| {
+        throw_error(&numbers::NumberError::InvalidLiteral(raw).to_string())
+      })
+  }
+
+  /// Returns the value of an integer without losing precision.
+  /// @returns The integer as a BigInt
+  /// @throws If the number isn't an integer
+  #[wasm_bindgen(js_name = asBigInt)]
+  pub fn as_big_int(&self) -> Result<js_sys::BigInt, JsValue> {
+    let digits = numbers::integer_digits(&self.inner.to_string())
+      .map_err(|e| throw_error(&e.to_string()))?;
+    js_sys::BigInt::new(&JsValue::from_str(&digits)).map_err(|e| e.into())
+  }
+
+  /// Sets the value of the number.
+  /// @param value - The number or BigInt to set
+  /// @param options - Optional options. When `preserveStyle` is true, the
+  /// current style is kept where possible: hexadecimal integers stay
+  /// hexadecimal, numbers with an exponent keep one, decimals keep their number
+  /// of fraction digits (ex. "1.50"), and a unary plus is kept.
+  /// @throws If the value is NaN, an infinity, or not a number
+  #[wasm_bindgen(js_name = setNumber)]
+  pub fn set_number(
+    &self,
+    value: JsValue,
+    options: Option<SetNumberOptionsObject>,
+  ) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let preserve_style = options
+      .and_then(|o| js_sys::Reflect::get(&o, &"preserveStyle".into()).ok())
+      .and_then(|v| v.as_bool())
+      .unwrap_or(false);
+    let raw = self.inner.to_string();
+    let style = preserve_style.then_some(raw.as_str());
+    let text = if value.is_bigint() {
+      let digits = js_sys::BigInt::from(value)
+        .to_string(10)
+        .map(String::from)
+        .map_err(JsValue::from)?;
+      match digits.parse::<i128>() {
+        Ok(integer) => numbers::write(numbers::Number::Integer(integer), style),
+        // too large to format, so write every digit
+        Err(_) => Ok(digits),
+      }
+    } else if let Some(number) = value.as_f64() {
+      numbers::write(numbers::Number::Float(number), style)
+    } else {
+      return Err(throw_error("Expected a number or BigInt"));
+    };
+    let text = text.map_err(|e| throw_error(&e.to_string()))?;
+    self.inner.set_raw_value(text);
+    Ok(())
+  }
+
+  /// Replaces this number literal with a new value.
+  /// @param replacement - The new value to replace this number with
+  /// @returns The new node that replaced this one, or undefined if this was the root value
+  #[wasm_bindgen(js_name = replaceWith)]
+  pub fn replace_with(
+    &self,
+    replacement: JsValue,
+  ) -> Result<Option<Node>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&replacement)?;
+    Ok(self.inner.clone().replace_with(cst_input).map(Node::new))
+  }
+
+  /// Removes this node from its parent.
+  /// After calling this method, the node is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+}
+
+/// Represents a boolean literal node in the CST.
+/// Provides methods for manipulating boolean values.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct BooleanLit {
+  inner: cst::CstBooleanLit,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl BooleanLit {
+  fn new(inner: cst::CstBooleanLit) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl BooleanLit {
+  /// Returns the boolean value (true or false).
+  /// @returns The boolean value
+  #[wasm_bindgen(js_name = value)]
+  pub fn value(&self) -> bool {
+    self.inner.value()
+  }
+
+  /// Sets the boolean value.
+  /// @param value - The new boolean value (true or false)
+  #[wasm_bindgen(js_name = setValue)]
+  pub fn set_value(&self, value: bool) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.set_value(value);
+    Ok(())
+  }
+
+  /// Replaces this boolean literal with a new value.
+  /// @param replacement - The new value to replace this boolean with
+  /// @returns The new node that replaced this one, or undefined if this was the root value
+  #[wasm_bindgen(js_name = replaceWith)]
+  pub fn replace_with(
+    &self,
+    replacement: JsValue,
+  ) -> Result<Option<Node>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&replacement)?;
+    Ok(self.inner.clone().replace_with(cst_input).map(Node::new))
+  }
+
+  /// Removes this node from its parent.
+  /// After calling this method, the node is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+}
+
+/// Represents a null keyword node in the CST.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct NullKeyword {
+  inner: cst::CstNullKeyword,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl NullKeyword {
+  fn new(inner: cst::CstNullKeyword) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl NullKeyword {
+  /// Replaces this null keyword with a new value.
+  /// @param replacement - The new value to replace this null with
+  /// @returns The new node that replaced this one, or undefined if this was the root value
+  #[wasm_bindgen(js_name = replaceWith)]
+  pub fn replace_with(
+    &self,
+    replacement: JsValue,
+  ) -> Result<Option<Node>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&replacement)?;
+    Ok(self.inner.clone().replace_with(cst_input).map(Node::new))
+  }
+
+  /// Removes this node from its parent.
+  /// After calling this method, the node is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+}
+
+/// Represents an unquoted word literal node in the CST.
+/// Used for unquoted property names when `allowLooseObjectPropertyNames` is enabled.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct WordLit {
+  inner: cst::CstWordLit,
+  _ancestors: Vec<CstContainerNode>,
+}
+
+impl WordLit {
+  fn new(inner: cst::CstWordLit) -> Self {
+    Self {
+      _ancestors: ancestors(&inner.clone().into()),
+      inner,
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl WordLit {
+  /// Returns the unquoted word value.
+  /// @returns The word literal as a string
+  #[wasm_bindgen(js_name = value)]
+  pub fn value(&self) -> String {
+    self.inner.to_string()
+  }
+
+  /// Sets the raw word value.
+  /// The value should be a valid unquoted identifier (alphanumeric and underscores).
+  /// @param value - The raw word string to set
+  #[wasm_bindgen(js_name = setRawValue)]
+  pub fn set_raw_value(&self, value: String) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.set_raw_value(value);
+    Ok(())
+  }
+
+  /// Replaces this word literal with a new value.
+  /// @param replacement - The new value to replace this word with
+  /// @returns The new node that replaced this one, or undefined if this was the root value
+  #[wasm_bindgen(js_name = replaceWith)]
+  pub fn replace_with(
+    &self,
+    replacement: JsValue,
+  ) -> Result<Option<Node>, JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    let cst_input = js_value_to_cst_input(&replacement)?;
+    Ok(self.inner.clone().replace_with(cst_input).map(Node::new))
+  }
+
+  /// Removes this node from its parent.
+  /// After calling this method, the node is detached from the CST and can no longer be used.
+  #[wasm_bindgen(js_name = remove)]
+  pub fn remove(self) -> Result<(), JsValue> {
+    ensure_attached(&self.inner.clone().into())?;
+    self.inner.remove();
+    Ok(())
+  }
+
+  /// Returns the parent node in the CST.
+  /// @returns The parent node, or undefined if this is the root
+  #[wasm_bindgen(js_name = parent)]
+  pub fn parent(&self) -> Option<Node> {
+    self
+      .inner
+      .parent()
+      .map(|p| Node::new(JsoncCstNode::Container(p)))
+  }
+
+  /// Returns all ancestor nodes from parent to root.
+  /// @returns Array of ancestor nodes
+  #[wasm_bindgen(js_name = ancestors)]
+  pub fn ancestors(&self) -> Vec<Node> {
+    self
+      .inner
+      .ancestors()
+      .map(|a| Node::new(JsoncCstNode::Container(a)))
+      .collect()
+  }
+
+  /// Returns the index of this node within its parent's children.
+  /// @returns The child index
+  #[wasm_bindgen(js_name = childIndex)]
+  pub fn child_index(&self) -> usize {
+    self.inner.child_index()
+  }
+
+  /// Returns the previous sibling node.
+  /// @returns The previous sibling, or undefined if this is the first child
+  #[wasm_bindgen(js_name = previousSibling)]
+  pub fn previous_sibling(&self) -> Option<Node> {
+    self.inner.previous_sibling().map(Node::new)
+  }
+
+  /// Returns all previous sibling nodes.
+  /// @returns Array of previous siblings
+  #[wasm_bindgen(js_name = previousSiblings)]
+  pub fn previous_siblings(&self) -> Vec<Node> {
+    self.inner.previous_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the next sibling node.
+  /// @returns The next sibling, or undefined if this is the last child
+  #[wasm_bindgen(js_name = nextSibling)]
+  pub fn next_sibling(&self) -> Option<Node> {
+    self.inner.next_sibling().map(Node::new)
+  }
+
+  /// Returns all next sibling nodes.
+  /// @returns Array of next siblings
+  #[wasm_bindgen(js_name = nextSiblings)]
+  pub fn next_siblings(&self) -> Vec<Node> {
+    self.inner.next_siblings().map(Node::new).collect()
+  }
+
+  /// Returns the root node of the document.
+  /// @returns The root node, or undefined if detached
+  #[wasm_bindgen(js_name = rootNode)]
+  pub fn root_node(&self) -> Option<RootNode> {
+    self.inner.root_node().map(|r| RootNode { inner: r })
+  }
+
+  /// Returns the indentation string used at this node's depth.
+  /// @returns The indentation string, or undefined if not applicable
+  #[wasm_bindgen(js_name = indentText)]
+  pub fn indent_text(&self) -> Option<String> {
+    self.inner.indent_text().map(|s| s.to_string())
+  }
+
+  /// Returns whether this node's container uses trailing commas.
+  /// @returns true if trailing commas are used
+  #[wasm_bindgen(js_name = usesTrailingCommas)]
+  pub fn uses_trailing_commas(&self) -> bool {
+    self.inner.uses_trailing_commas()
+  }
+}
@@ -10,25 +10,38 @@ let content = await Deno.readTextFile(dtsPath);
 // Replace 'any' types with 'JsonValue' for specific parameters
 // Be careful to only replace in function signatures, not in other contexts
 
+// append and insert also accept nodes, which are copied
+const valueOrNode = "JsonValue | Node | ObjectProp | JsonObject | JsonArray " +
+  "| StringLit | NumberLit | BooleanLit | NullKeyword | WordLit";
+
 const replacements: Array<[RegExp, string]> = [
   // RootNode.setValue
   [/setValue\(root_value: any\)/g, "setValue(root_value: JsonValue)"],
 
+  // RootNode.setPointer
+  [
+    /setPointer\(pointer: string, value: any\)/g,
+    "setPointer(pointer: string, value: JsonValue)",
+  ],
+
+  // RootNode.mergePatch and JsonObject.mergePatch
+  [/mergePatch\(patch: any\)/g, "mergePatch(patch: JsonValue)"],
+
   // JsonObject methods
   [
     /append\(key: string, value: any\)/g,
-    "append(key: string, value: JsonValue)",
+    `append(key: string, value: ${valueOrNode})`,
   ],
   [
     /insert\(index: number, key: string, value: any\)/g,
-    "insert(index: number, key: string, value: JsonValue)",
+    `insert(index: number, key: string, value: ${valueOrNode})`,
   ],
 
   // JsonArray methods
-  [/append\(value: any\)/g, "append(value: JsonValue)"],
+  [/append\(value: any\)/g, `append(value: ${valueOrNode})`],
   [
     /insert\(index: number, value: any\)/g,
-    "insert(index: number, value: JsonValue)",
+    `insert(index: number, value: ${valueOrNode})`,
   ],
 
   // ObjectProp.setValue
@@ -47,9 +60,19 @@ const replacements: Array<[RegExp, string]> = [
     /parseToValue\([^)]*\): any/g,
     (match) => match.replace(": any", ": JsonValue"),
   ],
-  [/toValue\(\): any/g, "toValue(): JsonValue"],
+  [
+    /toValue\([^)]*\): any/g,
+    (match) => match.replace(": any", ": JsonValue"),
+  ],
+
+  // NumberLit.setNumber
+  [/setNumber\(value: any,/g, "setNumber(value: number | bigint,"],
 
   [/newlineKind\(\): string/g, 'newlineKind(): "\\n" | "\\r\\n"'],
+  [
+    /setNewlineKind\(kind: string\)/g,
+    'setNewlineKind(kind: "\\n" | "\\r\\n")',
+  ],
 ];
 
 // Apply all replacements